@@ -1,14 +1,15 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyList};
+use pyo3::types::{PyAny, PyDict, PyList};
 use num_rational::Rational64;
 
 pub mod linalg;
 pub mod model;
 pub mod solvers;
 
-use crate::model::{Problem, Goal, Relation};
+use crate::linalg::rational_to_f64;
+use crate::model::{Problem, Goal, Relation, Tableau};
 use crate::solvers::{
-    BlandSimplexSolver, CyclingProneSolver, InitSource, ShadowVertexSimplexSolver,
+    BlandSimplexSolver, CyclingProneSolver, InitSource, Phase, ShadowVertexSimplexSolver,
     SimplexSolver, Solution, SolveStats, Status, Step, Solver,
 };
 
@@ -40,13 +41,17 @@ fn to_rational_vec(list: &Bound<'_, PyList>) -> PyResult<Vec<Rational64>> {
         .collect()
 }
 
-fn rational_to_f64(r: Rational64) -> f64 {
-    *r.numer() as f64 / *r.denom() as f64
+fn phase_to_str(p: Phase) -> &'static str {
+    match p {
+        Phase::Auxiliary => "auxiliary",
+        Phase::Objective => "objective",
+    }
 }
 
 fn status_to_str(s: Status) -> &'static str {
     match s {
         Status::InProgress => "in_progress",
+        Status::Feasible => "feasible",
         Status::Optimal => "optimal",
         Status::Infeasible => "infeasible",
         Status::Unbounded => "unbounded",
@@ -54,6 +59,14 @@ fn status_to_str(s: Status) -> &'static str {
     }
 }
 
+fn parse_goal(goal: &str) -> PyResult<Goal> {
+    goal.parse().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+}
+
+fn parse_relation(rel: &str) -> PyResult<Relation> {
+    rel.parse().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+}
+
 #[pyclass]
 pub struct PyProblem {
     pub(crate) inner: Problem<Rational64>,
@@ -70,47 +83,107 @@ impl PyProblem {
     #[new]
     #[pyo3(signature = (objective, goal="max"))]
     pub fn new(objective: &Bound<'_, PyList>, goal: &str) -> PyResult<Self> {
-        let g = match goal.to_lowercase().as_str() {
-            "min" => Goal::Min,
-            _ => Goal::Max,
-        };
         Ok(PyProblem {
-            inner: Problem::new(to_rational_vec(objective)?, g),
+            inner: Problem::new(to_rational_vec(objective)?, parse_goal(goal)?),
         })
     }
 
+    /// Builds a problem from dense data: objective `c`, rows of `a`, one
+    /// relation and one RHS entry per row.
+    #[staticmethod]
+    #[pyo3(signature = (objective, a, relations, b, goal="max"))]
+    pub fn from_dense(
+        objective: &Bound<'_, PyList>,
+        a: &Bound<'_, PyList>,
+        relations: Vec<String>,
+        b: &Bound<'_, PyList>,
+        goal: &str,
+    ) -> PyResult<Self> {
+        if a.len() != relations.len() || a.len() != b.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "len(A)={}, len(relations)={}, len(b)={} must all be equal",
+                a.len(),
+                relations.len(),
+                b.len()
+            )));
+        }
+        let mut inner = Problem::new(to_rational_vec(objective)?, parse_goal(goal)?);
+        for ((row, rel), rhs) in a.iter().zip(&relations).zip(b.iter()) {
+            let coeffs = to_rational_vec(row.cast::<PyList>()?)?;
+            inner.add_constraint(coeffs, parse_relation(rel)?, py_to_rational(&rhs)?);
+        }
+        inner
+            .dimensions_consistent()
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(PyProblem { inner })
+    }
+
     pub fn add_constraint(
         &mut self,
         coeffs: &Bound<'_, PyList>,
         rel: &str,
         rhs: &Bound<'_, PyAny>,
     ) -> PyResult<()> {
-        let r = match rel {
-            "<=" | "leq" => Relation::LessEqual,
-            ">=" | "geq" => Relation::GreaterEqual,
-            "=" | "==" | "eq" => Relation::Equal,
-            _ => {
-                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                    "Unknown relation '{}'; use '<=', '>=', or '='",
-                    rel
-                )));
-            }
-        };
+        let r = parse_relation(rel)?;
         self.inner
             .add_constraint(to_rational_vec(coeffs)?, r, py_to_rational(rhs)?);
+        if let Err(msg) = self.inner.dimensions_consistent() {
+            self.inner.constraints.pop();
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(msg));
+        }
+        Ok(())
+    }
+
+    /// Removes the constraint at `index`; later constraints shift down.
+    pub fn remove_constraint(&mut self, index: usize) -> PyResult<()> {
+        if index >= self.inner.constraints.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
+                "Constraint index {} out of range for {} constraints",
+                index,
+                self.inner.constraints.len()
+            )));
+        }
+        self.inner.remove_constraint(index);
         Ok(())
     }
 
+    /// Returns the dual problem (one variable per canonical constraint row).
+    pub fn dual(&self) -> PyProblem {
+        PyProblem {
+            inner: self.inner.dual(),
+        }
+    }
+
     pub fn to_tableau(&self) -> PyTableau {
         PyTableau {
             inner: self.inner.clone().into_tableau_form(),
         }
     }
 
-    pub fn __str__(&self) -> String {
+    /// Nonzero coefficients in the constraint matrix.
+    pub fn num_nonzeros(&self) -> usize {
+        self.inner.num_nonzeros()
+    }
+
+    /// Fraction of constraint-matrix entries that are nonzero.
+    pub fn density(&self) -> f64 {
+        self.inner.density()
+    }
+
+    /// Human-readable LP text (objective, constraints, non-negativity).
+    pub fn to_lp_string(&self) -> String {
         format!("{}", self.inner)
     }
 
+    /// Standard-form text: minimisation objective and equality rows with slacks.
+    pub fn to_standard_form_string(&self) -> String {
+        format!("{}", self.inner.clone().into_standard_form())
+    }
+
+    pub fn __str__(&self) -> String {
+        self.to_lp_string()
+    }
+
     pub fn __repr__(&self) -> String {
         self.__str__()
     }
@@ -124,14 +197,25 @@ pub struct PyStep {
     pub primal: Vec<f64>,
     #[pyo3(get)]
     pub objective_value: f64,
+    /// Objective as an exact `(numerator, denominator)` pair.
+    #[pyo3(get)]
+    pub objective_exact: (i64, i64),
     #[pyo3(get)]
     pub status: String,
     #[pyo3(get)]
     pub is_degenerate: bool,
     #[pyo3(get)]
+    pub degenerate_vertex: bool,
+    /// Basic variable of each constraint row.
+    #[pyo3(get)]
+    pub basis: Vec<usize>,
+    #[pyo3(get)]
     pub entering_var: Option<usize>,
     #[pyo3(get)]
     pub leaving_var: Option<usize>,
+    /// "auxiliary" or "objective" for phased solvers, otherwise None.
+    #[pyo3(get)]
+    pub phase: Option<String>,
 }
 
 #[pyclass]
@@ -140,8 +224,63 @@ pub struct PySolution {
     pub x: Vec<f64>,
     #[pyo3(get)]
     pub objective: f64,
+    /// Primal values as exact `(numerator, denominator)` pairs.
+    #[pyo3(get)]
+    pub x_exact: Vec<(i64, i64)>,
+    /// Objective as an exact `(numerator, denominator)` pair.
+    #[pyo3(get)]
+    pub objective_exact: (i64, i64),
     #[pyo3(get)]
     pub status: String,
+    /// Basic column of each constraint row at termination.
+    #[pyo3(get)]
+    pub basis: Vec<usize>,
+    /// Per-constraint slack (or surplus) at the final vertex.
+    #[pyo3(get)]
+    pub slacks: Vec<f64>,
+    /// Per-variable reduced cost from the final z-row.
+    #[pyo3(get)]
+    pub reduced_costs: Vec<f64>,
+    /// Direction along which the objective improves without bound, for
+    /// unbounded LPs; None otherwise.
+    #[pyo3(get)]
+    pub ray: Option<Vec<f64>>,
+}
+
+#[pymethods]
+impl PySolution {
+    /// Table of variable values, objective and status, using exact values.
+    pub fn __repr__(&self) -> String {
+        let exact = |(n, d): (i64, i64)| Rational64::new(n, d).to_string();
+        let values: Vec<String> = self.x_exact.iter().copied().map(exact).collect();
+        let width = values.iter().map(String::len).chain(["value".len()]).max().unwrap_or(0);
+        let name_width = format!("x{}", values.len().saturating_sub(1)).len().max("variable".len());
+
+        let mut out = format!("{:<name_width$}  {:>width$}\n", "variable", "value");
+        for (j, v) in values.iter().enumerate() {
+            out += &format!("{:<name_width$}  {:>width$}\n", format!("x{}", j), v);
+        }
+        out += &format!("objective: {}\nstatus: {}", exact(self.objective_exact), self.status);
+        out
+    }
+
+    /// Maps variable names to primal values; names default to `x0`, `x1`, ...
+    #[pyo3(signature = (names=None))]
+    pub fn as_dict<'py>(&self, py: Python<'py>, names: Option<Vec<String>>) -> PyResult<Bound<'py, PyDict>> {
+        let names = names.unwrap_or_else(|| (0..self.x.len()).map(|j| format!("x{}", j)).collect());
+        if names.len() != self.x.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Expected {} names, got {}",
+                self.x.len(),
+                names.len()
+            )));
+        }
+        let dict = PyDict::new(py);
+        for (name, value) in names.into_iter().zip(&self.x) {
+            dict.set_item(name, value)?;
+        }
+        Ok(dict)
+    }
 }
 
 #[pyclass]
@@ -174,6 +313,78 @@ impl PyTableau {
 
     pub fn num_rows(&self) -> usize { self.inner.rows() }
     pub fn num_cols(&self) -> usize { self.inner.cols() }
+
+    /// Entry at (row, col); the last row is the z-row and the last column the RHS.
+    pub fn get(&self, row: usize, col: usize) -> PyResult<f64> {
+        if row > self.inner.rows() || col >= self.inner.cols() {
+            return Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>("Tableau index out of range"));
+        }
+        Ok(rational_to_f64(self.inner.data[(row, col)]))
+    }
+
+    /// Basic column index for each constraint row.
+    pub fn basis(&self) -> Vec<usize> {
+        self.inner.basis.clone()
+    }
+
+    pub fn nonbasis(&self) -> Vec<usize> {
+        self.inner.nonbasis.clone()
+    }
+
+    /// Structural columns of the constraint rows, one list per row.
+    pub fn coefficients(&self) -> Vec<Vec<f64>> {
+        self.block(0..self.inner.m, 0..self.inner.n)
+    }
+
+    /// Slack columns of the constraint rows, one list per row.
+    pub fn slack_matrix(&self) -> Vec<Vec<f64>> {
+        self.block(0..self.inner.m, self.inner.n..self.inner.rhs_col())
+    }
+
+    /// RHS of each constraint row.
+    pub fn rhs(&self) -> Vec<f64> {
+        (0..self.inner.m).map(|i| rational_to_f64(self.inner.rhs(i))).collect()
+    }
+
+    /// z-row entries under the structural columns.
+    pub fn z_coeffs(&self) -> Vec<f64> {
+        self.block(self.inner.m..self.inner.m + 1, 0..self.inner.n).remove(0)
+    }
+
+    /// z-row entries under the slack columns.
+    pub fn z_slack(&self) -> Vec<f64> {
+        self.block(self.inner.m..self.inner.m + 1, self.inner.n..self.inner.rhs_col()).remove(0)
+    }
+
+    pub fn z_rhs(&self) -> f64 {
+        rational_to_f64(self.inner.z_rhs())
+    }
+
+    /// Every entry (z-row last, RHS column last) as an exact string such as `"1/3"`.
+    pub fn as_fractions(&self) -> Vec<Vec<String>> {
+        (0..self.inner.data.rows)
+            .map(|i| (0..self.inner.cols()).map(|j| self.inner.data[(i, j)].to_string()).collect())
+            .collect()
+    }
+
+    /// Pivots on constraint row `row` and variable column `col`.
+    pub fn pivot(&mut self, row: usize, col: usize) -> PyResult<()> {
+        if row >= self.inner.m || col >= self.inner.rhs_col() {
+            return Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>("Pivot position out of range"));
+        }
+        self.inner.pivot_exact_check(row, col).map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+    }
+
+    pub fn is_optimal(&self) -> bool {
+        self.inner.is_optimal()
+    }
+}
+
+impl PyTableau {
+    fn block(&self, rows: std::ops::Range<usize>, cols: std::ops::Range<usize>) -> Vec<Vec<f64>> {
+        rows.map(|i| cols.clone().map(|j| rational_to_f64(self.inner.data[(i, j)])).collect())
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -186,6 +397,12 @@ pub struct PySimplexSolver {
     initialized: bool,
 }
 
+impl Default for PySimplexSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[pymethods]
 impl PySimplexSolver {
     #[new]
@@ -207,7 +424,7 @@ impl PySimplexSolver {
         self.inner
             .find_initial_bfs()
             .map(|_| ())
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
     }
 
     pub fn step(&mut self) -> PyResult<PyStep> {
@@ -226,6 +443,21 @@ impl PySimplexSolver {
             .map(|s: &Step<Rational64>| step_to_py(s.clone()))
     }
 
+    /// Objective at the current basis; None before `init()`.
+    pub fn current_objective(&self) -> Option<f64> {
+        self.inner.current_objective().map(rational_to_f64)
+    }
+
+    /// Copy of the solver's current tableau; errors before `init()`.
+    pub fn get_tableau(&self) -> PyResult<PyTableau> {
+        self.inner
+            .tableau()
+            .map(|t| PyTableau { inner: t.clone() })
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>("Solver not initialized; call init(problem) first")
+            })
+    }
+
     pub fn is_done(&self) -> bool {
         self.inner.is_done()
     }
@@ -239,6 +471,14 @@ impl PySimplexSolver {
         self.initialized = true;
         run_solve_with_history(&mut self.inner, InitSource::Problem(problem.inner().clone()))
     }
+
+    /// Solves `problem`, returning each step together with a snapshot of the
+    /// tableau after it. Every snapshot is a full copy of the tableau, so
+    /// memory grows with steps x (m+1) x (n+m+1).
+    pub fn solve_with_tableau_history(&mut self, problem: &PyProblem) -> PyResult<(PySolution, Vec<(PyStep, PyTableau)>)> {
+        self.initialized = true;
+        run_solve_with_tableau_history(&mut self.inner, InitSource::Problem(problem.inner().clone()))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -251,6 +491,12 @@ pub struct PyBlandSimplexSolver {
     initialized: bool,
 }
 
+impl Default for PyBlandSimplexSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[pymethods]
 impl PyBlandSimplexSolver {
     #[new]
@@ -272,7 +518,7 @@ impl PyBlandSimplexSolver {
         self.inner
             .find_initial_bfs()
             .map(|_| ())
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
     }
 
     pub fn step(&mut self) -> PyResult<PyStep> {
@@ -291,6 +537,21 @@ impl PyBlandSimplexSolver {
             .map(|s: &Step<Rational64>| step_to_py(s.clone()))
     }
 
+    /// Objective at the current basis; None before `init()`.
+    pub fn current_objective(&self) -> Option<f64> {
+        self.inner.current_objective().map(rational_to_f64)
+    }
+
+    /// Copy of the solver's current tableau; errors before `init()`.
+    pub fn get_tableau(&self) -> PyResult<PyTableau> {
+        self.inner
+            .tableau()
+            .map(|t| PyTableau { inner: t.clone() })
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>("Solver not initialized; call init(problem) first")
+            })
+    }
+
     pub fn is_done(&self) -> bool {
         self.inner.is_done()
     }
@@ -316,6 +577,12 @@ pub struct PyCyclingProneSolver {
     initialized: bool,
 }
 
+impl Default for PyCyclingProneSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[pymethods]
 impl PyCyclingProneSolver {
     #[new]
@@ -337,7 +604,7 @@ impl PyCyclingProneSolver {
         self.inner
             .find_initial_bfs()
             .map(|_| ())
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
     }
 
     pub fn step(&mut self) -> PyResult<PyStep> {
@@ -356,6 +623,21 @@ impl PyCyclingProneSolver {
             .map(|s: &Step<Rational64>| step_to_py(s.clone()))
     }
 
+    /// Objective at the current basis; None before `init()`.
+    pub fn current_objective(&self) -> Option<f64> {
+        self.inner.current_objective().map(rational_to_f64)
+    }
+
+    /// Copy of the solver's current tableau; errors before `init()`.
+    pub fn get_tableau(&self) -> PyResult<PyTableau> {
+        self.inner
+            .tableau()
+            .map(|t| PyTableau { inner: t.clone() })
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>("Solver not initialized; call init(problem) first")
+            })
+    }
+
     pub fn is_done(&self) -> bool {
         self.inner.is_done()
     }
@@ -375,12 +657,21 @@ impl PyCyclingProneSolver {
 // Shadow vertex simplex solver
 // ---------------------------------------------------------------------------
 
+/// Solution, step history, (d, c) shadow points, and stats from a shadow solve.
+type ShadowHistory = (PySolution, Vec<PyStep>, Vec<(f64, f64)>, PySolveStats);
+
 #[pyclass]
 pub struct PyShadowVertexSimplexSolver {
     inner: ShadowVertexSimplexSolver<Rational64>,
     initialized: bool,
 }
 
+impl Default for PyShadowVertexSimplexSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[pymethods]
 impl PyShadowVertexSimplexSolver {
     #[new]
@@ -402,7 +693,7 @@ impl PyShadowVertexSimplexSolver {
         self.inner
             .find_initial_bfs()
             .map(|_| ())
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
     }
 
     pub fn step(&mut self) -> PyResult<PyStep> {
@@ -420,6 +711,21 @@ impl PyShadowVertexSimplexSolver {
             .map(|s: &Step<Rational64>| step_to_py(s.clone()))
     }
 
+    /// Objective at the current basis; None before `init()`.
+    pub fn current_objective(&self) -> Option<f64> {
+        self.inner.current_objective().map(rational_to_f64)
+    }
+
+    /// Copy of the solver's current tableau; errors before `init()`.
+    pub fn get_tableau(&self) -> PyResult<PyTableau> {
+        self.inner
+            .tableau()
+            .map(|t| PyTableau { inner: t.clone() })
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>("Solver not initialized; call init(problem) first")
+            })
+    }
+
     pub fn is_done(&self) -> bool {
         self.inner.is_done()
     }
@@ -434,6 +740,14 @@ impl PyShadowVertexSimplexSolver {
         run_solve_with_history(&mut self.inner, InitSource::Problem(problem.inner().clone()))
     }
 
+    /// Solves `problem`, returning each step together with a snapshot of the
+    /// tableau after it. Every snapshot is a full copy of the tableau, so
+    /// memory grows with steps x (m+1) x (n+m+1).
+    pub fn solve_with_tableau_history(&mut self, problem: &PyProblem) -> PyResult<(PySolution, Vec<(PyStep, PyTableau)>)> {
+        self.initialized = true;
+        run_solve_with_tableau_history(&mut self.inner, InitSource::Problem(problem.inner().clone()))
+    }
+
     pub fn set_auxiliary_objective(
         &mut self,
         d_coeffs: &Bound<'_, PyList>,
@@ -451,12 +765,12 @@ impl PyShadowVertexSimplexSolver {
     pub fn solve_with_shadow_history(
         &mut self,
         problem: &PyProblem,
-    ) -> PyResult<(PySolution, Vec<PyStep>, Vec<(f64, f64)>, PySolveStats)> {
+    ) -> PyResult<ShadowHistory> {
         self.initialized = true;
         let result = self
             .inner
             .solve_with_shadow_history(InitSource::Problem(problem.inner().clone()))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
 
         let mut stats = SolveStats::default();
         let history_steps: Vec<PyStep> = result
@@ -476,7 +790,8 @@ impl PyShadowVertexSimplexSolver {
             stats.total_pivots = last.iteration;
         }
 
-        let solution = solution_to_py(result.solution);
+        let mut solution = solution_to_py(result.solution);
+        attach_tableau_info(&mut solution, self.inner.tableau());
         let shadow_points: Vec<(f64, f64)> = result
             .shadow_points
             .iter()
@@ -495,10 +810,14 @@ fn step_to_py(s: Step<Rational64>) -> PyStep {
         iteration: s.iteration,
         primal: s.primal.iter().copied().map(rational_to_f64).collect(),
         objective_value: rational_to_f64(s.objective_value),
+        objective_exact: (*s.objective_value.numer(), *s.objective_value.denom()),
         status: status_to_str(s.status).to_string(),
         is_degenerate: s.is_degenerate,
+        degenerate_vertex: s.degenerate_vertex,
+        basis: s.basis,
         entering_var: s.entering_var,
         leaving_var: s.leaving_var,
+        phase: s.phase.map(|p| phase_to_str(p).to_string()),
     }
 }
 
@@ -506,10 +825,33 @@ fn solution_to_py(s: Solution<Rational64>) -> PySolution {
     PySolution {
         x: s.x.iter().copied().map(rational_to_f64).collect(),
         objective: rational_to_f64(s.objective),
+        x_exact: s.x.iter().map(|v| (*v.numer(), *v.denom())).collect(),
+        objective_exact: (*s.objective.numer(), *s.objective.denom()),
         status: status_to_str(s.status).to_string(),
+        basis: s.basis,
+        slacks: vec![],
+        reduced_costs: vec![],
+        ray: None,
     }
 }
 
+/// Fills per-constraint slacks and per-variable reduced costs from the final
+/// tableau when a vertex was reached, or the improving ray when the LP is
+/// unbounded.
+fn attach_tableau_info(sol: &mut PySolution, tab: Option<&Tableau<Rational64>>) {
+    let Some(tab) = tab else { return };
+    if sol.status == status_to_str(Status::Unbounded) {
+        sol.ray = tab
+            .unbounded_ray()
+            .map(|(_, direction)| direction.into_iter().map(rational_to_f64).collect());
+    }
+    if sol.x.is_empty() {
+        return;
+    }
+    sol.slacks = tab.slack_values().into_iter().map(rational_to_f64).collect();
+    sol.reduced_costs = tab.original_reduced_costs().into_iter().map(rational_to_f64).collect();
+}
+
 fn stats_to_py(s: &SolveStats) -> PySolveStats {
     PySolveStats {
         total_pivots: s.total_pivots,
@@ -524,61 +866,69 @@ where
     S: Solver<Rational64, Error = String>,
 {
     solver.init(source);
-    solver.find_initial_bfs().map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+    solver.find_initial_bfs().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
     let last = loop {
         let s = solver.step();
         if solver.is_done() {
             break s;
         }
     };
-    let sol = match last.status {
-        Status::Optimal | Status::Cycling => Solution { x: last.primal, objective: last.objective_value, status: last.status },
-        Status::Infeasible | Status::Unbounded => Solution { x: vec![], objective: Rational64::default(), status: last.status },
-        Status::InProgress => return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Solver stopped prematurely")),
-    };
-    Ok(solution_to_py(sol))
+    let mut py_sol = solution_to_py(terminal_solution(last)?);
+    attach_tableau_info(&mut py_sol, solver.tableau());
+    Ok(py_sol)
 }
 
 fn run_solve_with_history<S>(solver: &mut S, source: InitSource<Rational64>) -> PyResult<(PySolution, Vec<PyStep>, PySolveStats)>
 where
     S: Solver<Rational64, Error = String>,
 {
-    solver.init(source);
-    solver.find_initial_bfs().map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
-
-    let initial = solver.current_step();
-    let mut prev_primal = initial.primal.clone();
-    let mut history = vec![step_to_py(initial)];
-
-    let mut stats = SolveStats::default();
-
-    let mut last;
-    loop {
-        last = solver.step();
-        stats.total_pivots += 1;
-        if last.is_degenerate {
-            stats.degenerate_pivots += 1;
-        }
-        if solver.is_done() {
-            break;
-        }
-        if last.primal != prev_primal {
-            prev_primal = last.primal.clone();
-            history.push(step_to_py(last.clone()));
+    let (solution, steps) = solver
+        .solve_with_history(source)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+    // Python history: the starting vertex, then each non-terminal step that moved.
+    let mut history = vec![step_to_py(steps[0].clone())];
+    let mut prev_primal = &steps[0].primal;
+    for step in &steps[1..steps.len() - 1] {
+        if step.primal != *prev_primal {
+            prev_primal = &step.primal;
+            history.push(step_to_py(step.clone()));
         }
     }
+    let stats = SolveStats::from_steps(&steps);
 
-    stats.path_length = history.len();
-    stats.cycling_detected = last.status == Status::Cycling;
+    let mut py_sol = solution_to_py(solution);
+    attach_tableau_info(&mut py_sol, solver.tableau());
+    Ok((py_sol, history, stats_to_py(&stats)))
+}
 
-    let sol = match last.status {
-        Status::Optimal | Status::Cycling => {
-            Solution { x: last.primal, objective: last.objective_value, status: last.status }
+/// Like `run_solve`, also pairing every step with a copy of the tableau
+/// after it. Each snapshot clones the full `(m+1) x (n+m+1)` tableau.
+fn run_solve_with_tableau_history<S>(solver: &mut S, source: InitSource<Rational64>) -> PyResult<(PySolution, Vec<(PyStep, PyTableau)>)>
+where
+    S: Solver<Rational64, Error = String>,
+{
+    solver.init(source);
+    solver.find_initial_bfs().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+
+    let mut snapshots = Vec::new();
+    let last = loop {
+        let s = solver.step();
+        let tab = solver.tableau().expect("solver was initialized").clone();
+        snapshots.push((step_to_py(s.clone()), PyTableau { inner: tab }));
+        if solver.is_done() {
+            break s;
         }
-        Status::Infeasible | Status::Unbounded => Solution { x: vec![], objective: Rational64::default(), status: last.status },
-        Status::InProgress => return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Solver stopped prematurely")),
     };
-    Ok((solution_to_py(sol), history, stats_to_py(&stats)))
+    let mut py_sol = solution_to_py(terminal_solution(last)?);
+    attach_tableau_info(&mut py_sol, solver.tableau());
+    Ok((py_sol, snapshots))
+}
+
+/// Solution reported for the terminal step of a solve.
+fn terminal_solution(last: Step<Rational64>) -> PyResult<Solution<Rational64>> {
+    Solution::from_terminal_step(last)
+        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Solver stopped prematurely"))
 }
 
 #[pymodule]
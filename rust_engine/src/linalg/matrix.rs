@@ -1,6 +1,8 @@
 use std::ops::{Index, IndexMut, Range, Deref, DerefMut};
+use num_traits::{One, Zero};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Matrix<T> {
     pub rows: usize,
     pub cols: usize,
@@ -16,6 +18,37 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Builds a matrix from nested rows, or errors naming the first row
+    /// whose length differs from the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if let Some(i) = rows.iter().position(|r| r.len() != cols) {
+            return Err(format!("Row {} has {} entries; expected {}", i, rows[i].len(), cols));
+        }
+        Ok(Matrix { rows: rows.len(), cols, data: rows.into_iter().flatten().collect() })
+    }
+
+    /// Borrows each row in order as a slice of `data`.
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.rows).map(move |r| &self.data[r * self.cols..(r + 1) * self.cols])
+    }
+
+    /// Borrows the entries of column `c` from top to bottom.
+    pub fn col_iter(&self, c: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(c < self.cols, "Column index out of bounds");
+        self.data.iter().skip(c).step_by(self.cols)
+    }
+
+    /// Elements in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Mutable elements in row-major order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
     #[inline(always)]
     fn linear_index(&self, r: usize, c: usize) -> usize {
         debug_assert!(r < self.rows && c < self.cols);
@@ -62,6 +95,27 @@ impl<T> Matrix<T> {
         
         row1.swap_with_slice(row2);
     }
+
+    /// Removes row `r`, shifting later rows up.
+    pub fn delete_row(&mut self, r: usize) {
+        assert!(r < self.rows, "Row index out of bounds");
+        let range = self.row_range(r);
+        self.data.drain(range);
+        self.rows -= 1;
+    }
+
+    /// Removes column `c`, shifting later columns left.
+    pub fn delete_column(&mut self, c: usize) {
+        assert!(c < self.cols, "Column index out of bounds");
+        let cols = self.cols;
+        let mut i = 0;
+        self.data.retain(|_| {
+            let keep = i % cols != c;
+            i += 1;
+            keep
+        });
+        self.cols -= 1;
+    }
 }
 
 impl<T: Clone + Default> Matrix<T> {
@@ -85,7 +139,7 @@ impl<T: Clone + Default> Matrix<T> {
     }
 
     pub fn push_column(&mut self, new_col: Option<&[T]>) {
-        assert!(new_col.map_or(true, |col| col.len() == self.rows), "Column length must match matrix dimensions");
+        assert!(new_col.is_none_or(|col| col.len() == self.rows), "Column length must match matrix dimensions");
 
         let mut new_data = Vec::with_capacity((self.cols + 1) * self.rows);
 
@@ -101,6 +155,90 @@ impl<T: Clone + Default> Matrix<T> {
         self.cols += 1;
         self.data = new_data;
     }
+
+    /// Block-diagonal matrix with `blocks` placed corner to corner and default
+    /// (zero) entries elsewhere, e.g. the per-period rows of a staircase LP.
+    pub fn from_diagonal_blocks(blocks: &[Matrix<T>]) -> Self {
+        let rows = blocks.iter().map(|b| b.rows).sum();
+        let cols = blocks.iter().map(|b| b.cols).sum();
+        let mut out = Matrix::new(rows, cols);
+        let (mut r0, mut c0) = (0, 0);
+        for b in blocks {
+            for r in 0..b.rows {
+                for c in 0..b.cols {
+                    out[(r0 + r, c0 + c)] = b[(r, c)].clone();
+                }
+            }
+            r0 += b.rows;
+            c0 += b.cols;
+        }
+        out
+    }
+
+    /// Appends an owned row, moving its elements into the matrix.
+    pub fn append_row_vec(&mut self, new_row: Vec<T>) {
+        assert_eq!(new_row.len(), self.cols, "Row length must match matrix dimensions");
+        self.data.extend(new_row);
+        self.rows += 1;
+    }
+
+    /// Appends an owned column, moving its elements into the matrix.
+    pub fn append_col_vec(&mut self, new_col: Vec<T>) {
+        assert_eq!(new_col.len(), self.rows, "Column length must match matrix dimensions");
+
+        let mut new_data = Vec::with_capacity((self.cols + 1) * self.rows);
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        for val in new_col {
+            new_data.extend(old.by_ref().take(self.cols));
+            new_data.push(val);
+        }
+
+        self.cols += 1;
+        self.data = new_data;
+    }
+}
+
+impl<T: Zero + One + Clone> Matrix<T> {
+    /// `n x n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        Self::from_diagonal(&vec![T::one(); n])
+    }
+
+    /// Square matrix with `diag` on the diagonal and zeros elsewhere.
+    pub fn from_diagonal(diag: &[T]) -> Self {
+        let n = diag.len();
+        let mut data = vec![T::zero(); n * n];
+        for (i, v) in diag.iter().enumerate() {
+            data[i * n + i] = v.clone();
+        }
+        Matrix { rows: n, cols: n, data }
+    }
+}
+
+impl<T: Zero + One + PartialEq> Matrix<T> {
+    /// True for a square matrix with ones on the diagonal and zeros elsewhere.
+    pub fn is_identity(&self) -> bool {
+        self.rows == self.cols
+            && self.data.iter().enumerate().all(|(i, v)| {
+                if i / self.cols == i % self.cols { v.is_one() } else { v.is_zero() }
+            })
+    }
+}
+
+impl<T: Zero> Matrix<T> {
+    /// True when every entry is zero (vacuously true for an empty matrix).
+    pub fn is_zero(&self) -> bool {
+        self.iter().all(|v| v.is_zero())
+    }
+}
+
+fn assert_permutation(perm: &[usize], len: usize) {
+    assert_eq!(perm.len(), len, "Permutation length must match matrix dimension");
+    let mut seen = vec![false; len];
+    for &i in perm {
+        assert!(i < len && !seen[i], "Permutation must use each index in 0..{} exactly once", len);
+        seen[i] = true;
+    }
 }
 
 impl<T> Index<(usize, usize)> for Matrix<T> {
@@ -131,6 +269,12 @@ pub struct RowMut<'a, T> {
 }
 
 impl<T: Clone> Matrix<T> {
+    /// `rows x cols` matrix copied from row-major `data`.
+    pub fn from_slice(rows: usize, cols: usize, data: &[T]) -> Self {
+        assert_eq!(data.len(), rows * cols, "Data length must equal rows * cols");
+        Matrix { rows, cols, data: data.to_vec() }
+    }
+
     pub fn row(&self, r: usize) -> Row<T> {
         let range = self.row_range(r);
         Row { data: self.data[range].to_owned() }
@@ -140,6 +284,71 @@ impl<T: Clone> Matrix<T> {
         let range = self.row_range(r);
         RowMut { data: &mut self.data[range] }
     }
+
+    /// Iterates the rows in order, each as an owned `Row`.
+    pub fn iter_rows(&self) -> impl Iterator<Item = Row<T>> + '_ {
+        (0..self.rows).map(|r| self.row(r))
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+
+    pub fn set_row(&mut self, r: usize, data: &[T]) {
+        assert!(r < self.rows, "Row index out of bounds");
+        assert_eq!(data.len(), self.cols, "Row length must match matrix dimensions");
+        let range = self.row_range(r);
+        self.data[range].clone_from_slice(data);
+    }
+
+    pub fn set_column(&mut self, c: usize, data: &[T]) {
+        assert!(c < self.cols, "Column index out of bounds");
+        assert_eq!(data.len(), self.rows, "Column length must match matrix dimensions");
+        for (r, val) in data.iter().enumerate() {
+            let idx = self.linear_index(r, c);
+            self.data[idx] = val.clone();
+        }
+    }
+
+    /// Row-of-rows copy, the natural shape for JSON and Python lists.
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>> {
+        self.iter_rows().map(|r| r.data).collect()
+    }
+
+    /// Reorders rows so that new row `i` is old row `perm[i]`.
+    pub fn permute_rows(&mut self, perm: &[usize]) {
+        assert_permutation(perm, self.rows);
+        let data = perm.iter().flat_map(|&r| self.data[self.row_range(r)].iter().cloned()).collect();
+        self.data = data;
+    }
+
+    /// Reorders columns so that new column `j` is old column `perm[j]`.
+    pub fn permute_columns(&mut self, perm: &[usize]) {
+        assert_permutation(perm, self.cols);
+        let data = (0..self.rows)
+            .flat_map(|r| perm.iter().map(move |&c| (r, c)))
+            .map(|(r, c)| self[(r, c)].clone())
+            .collect();
+        self.data = data;
+    }
+
+    /// Submatrix with row `row` and column `col` removed.
+    pub fn minor(&self, row: usize, col: usize) -> Matrix<T> {
+        assert!(row < self.rows && col < self.cols, "Minor index out of bounds");
+        let data = (0..self.rows)
+            .filter(|&r| r != row)
+            .flat_map(|r| (0..self.cols).filter(move |&c| c != col).map(move |c| (r, c)))
+            .map(|(r, c)| self[(r, c)].clone())
+            .collect();
+        Matrix { rows: self.rows - 1, cols: self.cols - 1, data }
+    }
+}
+
+impl<'a, T> RowMut<'a, T> {
+    /// Iterates the row mutably together with each element's column index.
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.data.iter_mut().enumerate()
+    }
 }
 
 impl<T> Deref for Row<T> {
@@ -128,7 +128,9 @@ where T: Clone + SubAssign
     }
 }
 
-// Element-wise multiplication (Hadamard)
+/// Element-wise (Hadamard) product, NOT the matrix product: `&a * &b` needs
+/// equal shapes and multiplies entry by entry; use `a.dot(&b)` for the matrix
+/// product. Panics on a shape mismatch; see `try_hadamard`.
 impl<T> Mul<&Matrix<T>> for &Matrix<T>
 where T: Clone + Mul<Output = T>
 {
@@ -144,6 +146,22 @@ where T: Clone + Mul<Output = T>
     }
 }
 
+impl<T> Matrix<T>
+where T: Clone + Mul<Output = T>
+{
+    /// Element-wise product like `&self * other`, but returns an error
+    /// instead of panicking when the shapes differ.
+    pub fn try_hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, String> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(format!(
+                "Cannot take element-wise product of {}x{} and {}x{}: shapes must match",
+                self.rows, self.cols, other.rows, other.cols
+            ));
+        }
+        Ok(self * other)
+    }
+}
+
 impl<T> MulAssign<&Matrix<T>> for Matrix<T>
 where T: Clone + MulAssign
 {
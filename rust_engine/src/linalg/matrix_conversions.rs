@@ -0,0 +1,55 @@
+use crate::linalg::Matrix;
+use num_rational::Rational64;
+use num_traits::float::FloatCore;
+
+/// Nearest `f64` to an exact rational.
+pub fn rational_to_f64(r: Rational64) -> f64 {
+    *r.numer() as f64 / *r.denom() as f64
+}
+
+/// Exact rational equal to `f`, or `None` when `f` is not finite or its
+/// binary expansion does not fit in an `i64` numerator/denominator.
+pub fn rational_from_f64_exact(f: f64) -> Option<Rational64> {
+    if !f.is_finite() {
+        return None;
+    }
+    let (mut mantissa, mut exponent, sign) = f.integer_decode();
+    if mantissa == 0 {
+        return Some(Rational64::from_integer(0));
+    }
+    while mantissa % 2 == 0 && exponent < 0 {
+        mantissa /= 2;
+        exponent += 1;
+    }
+    let numer = i64::try_from(mantissa).ok()?;
+    let value = if exponent >= 0 {
+        Rational64::from_integer(numer.checked_mul(1i64.checked_shl(exponent as u32)?)?)
+    } else if exponent > -63 {
+        Rational64::new(numer, 1i64 << -exponent)
+    } else {
+        return None;
+    };
+    Some(if sign < 0 { -value } else { value })
+}
+
+impl Matrix<Rational64> {
+    /// Element-wise conversion to floating point, e.g. for plotting.
+    pub fn to_float_matrix(&self) -> Matrix<f64> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().copied().map(rational_to_f64).collect(),
+        }
+    }
+
+    /// Exact element-wise conversion from floating point; panics on
+    /// non-finite or unrepresentable entries.
+    pub fn from_float_matrix(m: &Matrix<f64>) -> Matrix<Rational64> {
+        let data = m
+            .data
+            .iter()
+            .map(|&f| rational_from_f64_exact(f).unwrap_or_else(|| panic!("{} has no exact Rational64 form", f)))
+            .collect();
+        Matrix { rows: m.rows, cols: m.cols, data }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::linalg::Matrix;
-use std::ops::{Add, Mul};
+use num_traits::{One, Zero};
+use std::ops::{Add, Div, Mul, Sub};
 
 impl<T> Matrix<T>
 where
@@ -19,4 +20,149 @@ where
         }
         result
     }
-}
\ No newline at end of file
+
+    /// Like `dot`, but returns an error instead of panicking when
+    /// `self.cols != other.rows`.
+    pub fn checked_dot(&self, other: &Matrix<T>) -> Result<Matrix<T>, String> {
+        if self.cols != other.rows {
+            return Err(format!(
+                "Cannot multiply {}x{} by {}x{}: left columns must equal right rows",
+                self.rows, self.cols, other.rows, other.cols
+            ));
+        }
+        Ok(self.dot(other))
+    }
+
+    /// Kronecker product: a `(rows_a*rows_b) x (cols_a*cols_b)` block matrix
+    /// whose `(i, j)` block is `self[(i, j)] * other`.
+    pub fn kron(&self, other: &Matrix<T>) -> Matrix<T> {
+        let mut result = Matrix::new(self.rows * other.rows, self.cols * other.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                for k in 0..other.rows {
+                    for l in 0..other.cols {
+                        result[(i * other.rows + k, j * other.cols + l)] =
+                            self[(i, j)].clone() * other[(k, l)].clone();
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// `self^exp` by exponentiation-by-squaring over `dot`; `exp == 0` gives
+    /// the identity. Panics unless the matrix is square.
+    pub fn pow(&self, exp: u32) -> Matrix<T>
+    where
+        T: Zero + One,
+    {
+        assert_eq!(self.rows, self.cols, "pow requires a square matrix");
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.dot(&base);
+            }
+            e >>= 1;
+            if e > 0 {
+                base = base.dot(&base);
+            }
+        }
+        result
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Determinant by cofactor expansion along the first row. Exact but
+    /// O(n!), so intended for small matrices; the empty matrix has determinant 1.
+    pub fn determinant(&self) -> T {
+        assert_eq!(self.rows, self.cols, "determinant requires a square matrix");
+        if self.rows == 0 {
+            return T::one();
+        }
+        (0..self.cols).fold(T::zero(), |acc, j| acc + self[(0, j)].clone() * self.cofactor(0, j))
+    }
+
+    /// Signed minor `(-1)^(row+col) * det(minor(row, col))`.
+    pub fn cofactor(&self, row: usize, col: usize) -> T {
+        let det = self.minor(row, col).determinant();
+        if (row + col).is_multiple_of(2) { det } else { T::zero() - det }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Zero + PartialOrd + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Solves the square system `self * x = b` by Gauss-Jordan elimination,
+    /// pivoting on the largest-magnitude entry of each column so float
+    /// matrices stay stable. Returns `None` when the matrix is singular.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        assert_eq!(self.rows, self.cols, "solve requires a square matrix");
+        assert_eq!(b.len(), self.rows, "Right-hand side length must match matrix dimensions");
+
+        let magnitude = |v: T| if v < T::zero() { T::zero() - v } else { v };
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut x = b.to_vec();
+        for col in 0..n {
+            let pivot = (col..n)
+                .filter(|&r| !a[(r, col)].is_zero())
+                .fold(None, |best: Option<usize>, r| match best {
+                    Some(b) if magnitude(a[(b, col)]) >= magnitude(a[(r, col)]) => Some(b),
+                    _ => Some(r),
+                })?;
+            a.swap_rows(col, pivot);
+            x.swap(col, pivot);
+
+            let p = a[(col, col)];
+            for c in col..n {
+                a[(col, c)] = a[(col, c)] / p;
+            }
+            x[col] = x[col] / p;
+
+            for r in 0..n {
+                let factor = a[(r, col)];
+                if r == col || factor.is_zero() {
+                    continue;
+                }
+                for c in col..n {
+                    a[(r, c)] = a[(r, c)] - factor * a[(col, c)];
+                }
+                x[r] = x[r] - factor * x[col];
+            }
+        }
+        Some(x)
+    }
+
+    /// Rank by exact row reduction: the number of pivot rows found.
+    /// Works on non-square matrices.
+    pub fn rank(&self) -> usize {
+        let mut a = self.clone();
+        let mut rank = 0;
+        for col in 0..a.cols {
+            if rank == a.rows {
+                break;
+            }
+            let Some(pivot) = (rank..a.rows).find(|&r| !a[(r, col)].is_zero()) else { continue };
+            a.swap_rows(rank, pivot);
+
+            let p = a[(rank, col)];
+            for r in rank + 1..a.rows {
+                let factor = a[(r, col)] / p;
+                if factor.is_zero() {
+                    continue;
+                }
+                for c in col..a.cols {
+                    a[(r, c)] = a[(r, c)] - factor * a[(rank, c)];
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+}
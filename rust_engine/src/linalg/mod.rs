@@ -2,8 +2,14 @@ pub mod matrix;
 pub mod matrix_operations;
 pub mod matrix_arithmetic;
 pub mod matrix_row_operations;
+pub mod matrix_conversions;
+pub mod scalar;
+pub mod sparse_matrix;
 
 pub use matrix::{Matrix, Row, RowMut};
+pub use scalar::{powi, reciprocal};
+pub use sparse_matrix::SparseMatrix;
+pub use matrix_conversions::{rational_from_f64_exact, rational_to_f64};
 
 #[cfg(test)]
 mod tests {
@@ -159,6 +165,222 @@ mod tests {
         assert_eq!(c[(1,1)], 154); // 4*8 + 5*10 + 6*12
     }
 
+    #[test]
+    fn test_checked_dot() {
+        let mut a = Matrix::<i32>::new(2,3);
+        let mut b = Matrix::<i32>::new(3,1);
+        a[(0,0)] = 1; a[(0,1)] = 2; a[(0,2)] = 3;
+        a[(1,0)] = 4; a[(1,1)] = 5; a[(1,2)] = 6;
+        b[(0,0)] = 1; b[(1,0)] = 1; b[(2,0)] = 1;
+
+        let c = a.checked_dot(&b).unwrap();
+        assert_eq!((c.rows, c.cols), (2, 1));
+        assert_eq!(c[(0,0)], 6);
+        assert_eq!(c[(1,0)], 15);
+
+        let err = b.checked_dot(&b).unwrap_err();
+        assert!(err.contains("3x1 by 3x1"), "{}", err);
+    }
+
+    #[test]
+    fn test_minor_and_cofactor() {
+        let mut a = Matrix::<i32>::new(3,3);
+        a[(0,0)] = 3; a[(0,1)] = 0; a[(0,2)] = 1;
+        a[(1,0)] = 1; a[(1,1)] = 3; a[(1,2)] = 2;
+        a[(2,0)] = 1; a[(2,1)] = 1; a[(2,2)] = 1;
+
+        let m = a.minor(0, 1);
+        assert_eq!((m.rows, m.cols), (2, 2));
+        assert_eq!(m.data, vec![1, 2, 1, 1]);
+
+        assert_eq!(a.cofactor(0, 1), 1); // -(1*1 - 2*1)
+        assert_eq!(a.determinant(), 1);  // 3*(3-2) + 0 + 1*(1-3)
+    }
+
+    #[test]
+    fn test_iter_row_major() {
+        let mut a = Matrix::<i32>::new(2,3);
+        a[(0,0)] = 1; a[(0,1)] = 2; a[(0,2)] = 3;
+        a[(1,0)] = 4; a[(1,1)] = 5; a[(1,2)] = 6;
+
+        assert_eq!(a.iter().sum::<i32>(), 21);
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+
+        a.iter_mut().for_each(|v| *v *= 2);
+        assert_eq!(a[(1,2)], 12);
+    }
+
+    #[test]
+    fn test_permute_rows_and_columns() {
+        let mut a = Matrix::<i32>::new(3,3);
+        a[(0,0)] = 1; a[(0,1)] = 2; a[(0,2)] = 3;
+        a[(1,0)] = 4; a[(1,1)] = 5; a[(1,2)] = 6;
+        a[(2,0)] = 7; a[(2,1)] = 8; a[(2,2)] = 9;
+
+        a.permute_rows(&[2, 0, 1]);
+        assert_eq!(a.data, vec![7, 8, 9, 1, 2, 3, 4, 5, 6]);
+
+        a.permute_columns(&[2, 0, 1]);
+        assert_eq!(a.data, vec![9, 7, 8, 3, 1, 2, 6, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly once")]
+    fn test_permute_rows_rejects_repeated_index() {
+        let mut a = Matrix::<i32>::new(3,3);
+        a.permute_rows(&[0, 0, 1]);
+    }
+
+    #[test]
+    fn test_to_nested_vec() {
+        let mut a = Matrix::<i32>::new(2,3);
+        a[(0,0)] = 1; a[(0,1)] = 2; a[(0,2)] = 3;
+        a[(1,0)] = 4; a[(1,1)] = 5; a[(1,2)] = 6;
+
+        assert_eq!(a.to_nested_vec(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_sparse_matrix_get_set_and_dense_bridge() {
+        let mut s = SparseMatrix::<i32>::new(3, 4);
+        s.set(0, 3, 7);
+        s.set(2, 1, -2);
+        s.set(0, 1, 5);
+        assert_eq!(s.nnz(), 3);
+        assert_eq!(s.get(0, 1), 5);
+        assert_eq!(s.get(1, 1), 0);
+        assert_eq!(s.row(0).collect::<Vec<_>>(), vec![(1, &5), (3, &7)]);
+
+        s.set(0, 3, 0);
+        assert_eq!(s.nnz(), 2);
+
+        let dense = s.to_dense();
+        assert_eq!(dense.data, vec![0, 5, 0, 0, 0, 0, 0, 0, 0, -2, 0, 0]);
+        assert_eq!(SparseMatrix::from_dense(&dense), s);
+        assert_eq!(s.iter_rows().map(|r| r.count()).collect::<Vec<_>>(), vec![1, 0, 1]);
+    }
+
+    #[test]
+    fn test_try_hadamard() {
+        let mut a = Matrix::<i32>::new(2, 2);
+        a.data = vec![1, 2, 3, 4];
+        let mut b = Matrix::<i32>::new(2, 2);
+        b.data = vec![5, 6, 7, 8];
+        assert_eq!(a.try_hadamard(&b).unwrap().data, vec![5, 12, 21, 32]);
+        assert!(a.try_hadamard(&Matrix::<i32>::new(2, 3)).is_err());
+    }
+
+    #[test]
+    fn test_mul_is_elementwise_not_dot() {
+        let mut a = Matrix::<i32>::new(2, 2);
+        a.data = vec![1, 2, 3, 4];
+        assert_eq!((&a * &a).data, vec![1, 4, 9, 16]);
+        assert_eq!(a.dot(&a).data, vec![7, 10, 15, 22]);
+    }
+
+    #[test]
+    fn test_identity_and_from_diagonal() {
+        let id = Matrix::<i32>::identity(3);
+        assert_eq!((id.rows, id.cols), (3, 3));
+        for r in 0..3 {
+            for c in 0..3 {
+                assert_eq!(id[(r, c)], if r == c { 1 } else { 0 });
+            }
+        }
+        assert!(id.is_identity());
+
+        let d = Matrix::from_diagonal(&[2, -1]);
+        assert_eq!(d.data, vec![2, 0, 0, -1]);
+        assert!(Matrix::<i32>::identity(0).data.is_empty());
+    }
+
+    #[test]
+    fn test_from_rows_and_from_slice() {
+        let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!((m.rows, m.cols), (2, 3));
+        assert_eq!(m.data, vec![1, 2, 3, 4, 5, 6]);
+
+        let err = Matrix::from_rows(vec![vec![1, 2], vec![3]]).unwrap_err();
+        assert_eq!(err, "Row 1 has 1 entries; expected 2");
+
+        let s = Matrix::from_slice(3, 2, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!((s.rows, s.cols), (3, 2));
+        assert_eq!(s[(2, 0)], 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Data length must equal rows * cols")]
+    fn test_from_slice_wrong_length_panics() {
+        Matrix::from_slice(2, 2, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_rows_iter_and_col_iter() {
+        let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        let col_sums: Vec<i32> = (0..m.cols).map(|c| m.col_iter(c).sum()).collect();
+        assert_eq!(col_sums, vec![12, 15, 18]);
+
+        let rows: Vec<&[i32]> = m.rows_iter().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6], &[7, 8, 9]]);
+        assert_eq!(Matrix::<i32>::new(0, 3).rows_iter().count(), 0);
+        assert_eq!(Matrix::<i32>::new(2, 0).rows_iter().count(), 2);
+    }
+
+    #[test]
+    fn test_delete_middle_row_and_column() {
+        let mut m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+        m.delete_row(1);
+        assert_eq!((m.rows, m.cols), (2, 3));
+        assert_eq!(m.data, vec![1, 2, 3, 7, 8, 9]);
+
+        m.delete_column(1);
+        assert_eq!((m.rows, m.cols), (2, 2));
+        assert_eq!(m.data, vec![1, 3, 7, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index out of bounds")]
+    fn test_delete_column_out_of_bounds_panics() {
+        Matrix::<i32>::new(2, 2).delete_column(2);
+    }
+
+    #[test]
+    fn test_kron() {
+        let mut a = Matrix::<i32>::new(2, 2);
+        a[(0,0)] = 1; a[(0,1)] = 2;
+        a[(1,0)] = 3; a[(1,1)] = 4;
+
+        let mut b = Matrix::<i32>::new(2, 2);
+        b[(0,0)] = 0; b[(0,1)] = 5;
+        b[(1,0)] = 6; b[(1,1)] = 7;
+
+        let k = a.kron(&b);
+        assert_eq!(k.rows, 4);
+        assert_eq!(k.cols, 4);
+        assert_eq!(k.data, vec![
+            0,  5,  0, 10,
+            6,  7, 12, 14,
+            0, 15,  0, 20,
+           18, 21, 24, 28,
+        ]);
+    }
+
+    #[test]
+    fn test_reciprocal_and_powi() {
+        use num_rational::Rational64;
+
+        assert_eq!(reciprocal(Rational64::new(3, 4)), Rational64::new(4, 3));
+        assert_eq!(powi(Rational64::new(2, 3), 3), Rational64::new(8, 27));
+        assert_eq!(powi(Rational64::new(2, 3), -2), Rational64::new(9, 4));
+        assert_eq!(powi(Rational64::new(5, 7), 0), Rational64::new(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "reciprocal of zero")]
+    fn test_reciprocal_of_zero_panics() {
+        reciprocal(num_rational::Rational64::new(0, 1));
+    }
+
     #[test]
     fn test_push_row() {
         let mut m = Matrix::<i32>::new(2, 3);
@@ -228,6 +450,158 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_append_computed_row_and_column() {
+        let mut m = Matrix::<i32>::new(2, 2);
+        m[(0, 0)] = 1; m[(0, 1)] = 2;
+        m[(1, 0)] = 3; m[(1, 1)] = 4;
+
+        let sums: Vec<i32> = (0..2).map(|c| m[(0, c)] + m[(1, c)]).collect();
+        m.append_row_vec(sums);
+        assert_eq!(m.rows, 3);
+        assert_eq!(m.data, vec![1, 2, 3, 4, 4, 6]);
+
+        let row_totals: Vec<i32> = (0..3).map(|r| m[(r, 0)] + m[(r, 1)]).collect();
+        m.append_col_vec(row_totals);
+        assert_eq!(m.cols, 3);
+        assert_eq!(m.data, vec![1, 2, 3, 3, 4, 7, 4, 6, 10]);
+    }
+
+    #[test]
+    fn test_solve_3x3_system() {
+        use num_rational::Rational64;
+        let r = Rational64::from_integer;
+        let mut a = Matrix::<Rational64>::new(0, 3);
+        a.push_row(&[r(0), r(2), r(1)]);
+        a.push_row(&[r(1), r(1), r(1)]);
+        a.push_row(&[r(2), r(1), r(-1)]);
+        // x = (1, -1, 3)
+        let x = a.solve(&[r(1), r(3), r(-2)]).unwrap();
+        assert_eq!(x, vec![r(1), r(-1), r(3)]);
+    }
+
+    #[test]
+    fn test_solve_singular_returns_none() {
+        use num_rational::Rational64;
+        let r = Rational64::from_integer;
+        let mut a = Matrix::<Rational64>::new(0, 2);
+        a.push_row(&[r(1), r(2)]);
+        a.push_row(&[r(2), r(4)]);
+        assert!(a.solve(&[r(1), r(2)]).is_none());
+    }
+
+    #[test]
+    fn test_solve_pivots_on_largest_magnitude() {
+        let mut a = Matrix::<f64>::new(0, 2);
+        a.push_row(&[1e-20, 1.0]);
+        a.push_row(&[1.0, 1.0]);
+        // Pivoting on 1e-20 would round x[0] to 0.
+        let x = a.solve(&[1.0, 2.0]).unwrap();
+        assert_eq!(x, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_rank_full_dependent_and_zero() {
+        use num_rational::Rational64;
+        let r = Rational64::from_integer;
+        let mut full = Matrix::<Rational64>::new(0, 3);
+        full.push_row(&[r(0), r(2), r(1)]);
+        full.push_row(&[r(1), r(1), r(1)]);
+        full.push_row(&[r(2), r(1), r(-1)]);
+        assert_eq!(full.rank(), 3);
+
+        // Third row = first + 2 * second.
+        let mut dependent = Matrix::<Rational64>::new(0, 4);
+        dependent.push_row(&[r(1), r(2), r(0), r(3)]);
+        dependent.push_row(&[r(0), r(1), r(1), r(-1)]);
+        dependent.push_row(&[r(1), r(4), r(2), r(1)]);
+        assert_eq!(dependent.rank(), 2);
+
+        assert_eq!(Matrix::<Rational64>::new(2, 3).rank(), 0);
+    }
+
+    #[test]
+    fn test_float_matrix_round_trip() {
+        use num_rational::Rational64;
+        let mut m = Matrix::<f64>::new(2, 2);
+        m[(0, 0)] = 0.5; m[(0, 1)] = -3.0;
+        m[(1, 0)] = 0.125; m[(1, 1)] = 1024.75;
+
+        let exact = Matrix::<Rational64>::from_float_matrix(&m);
+        assert_eq!(exact[(0, 0)], Rational64::new(1, 2));
+        assert_eq!(exact[(1, 1)], Rational64::new(4099, 4));
+        assert_eq!(exact.to_float_matrix().data, m.data);
+
+        assert_eq!(rational_from_f64_exact(0.1), Some(Rational64::new(3602879701896397, 1 << 55)));
+        assert_eq!(rational_from_f64_exact(f64::NAN), None);
+        assert_eq!(rational_from_f64_exact(1e300), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        let mut a = Matrix::<i32>::new(2, 2);
+        a[(0, 0)] = 1; a[(0, 1)] = 1;
+        a[(1, 0)] = 1; a[(1, 1)] = 0;
+
+        assert!(a.pow(0).is_identity());
+        assert_eq!(a.pow(1).data, a.data);
+        assert_eq!(a.pow(3).data, a.dot(&a).dot(&a).data);
+        assert_eq!(a.pow(3).data, vec![3, 2, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "pow requires a square matrix")]
+    fn test_pow_non_square_panics() {
+        Matrix::<i32>::new(2, 3).pow(2);
+    }
+
+    #[test]
+    fn test_is_identity_and_is_zero() {
+        let mut m = Matrix::<i32>::new(3, 3);
+        assert!(m.is_zero());
+        assert!(!m.is_identity());
+
+        for i in 0..3 { m[(i, i)] = 1; }
+        assert!(m.is_identity());
+        assert!(!m.is_zero());
+
+        m[(0, 2)] = 1;
+        assert!(!m.is_identity());
+
+        let rect = Matrix::<i32>::new(2, 3);
+        assert!(!rect.is_identity());
+    }
+
+    #[test]
+    fn test_iter_rows() {
+        let mut m = Matrix::<i32>::new(0, 2);
+        m.push_row(&[1, 2]);
+        m.push_row(&[3, 4]);
+        m.push_row(&[5, 6]);
+
+        let rows: Vec<Vec<i32>> = m.iter_rows().map(|r| r.data).collect();
+        assert_eq!(rows, vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        assert_eq!(m.iter_rows().count(), 3);
+    }
+
+    #[test]
+    fn test_fill_and_set_row_column() {
+        let mut m = Matrix::<i32>::new(3, 3);
+        m.fill(7);
+        assert_eq!(m.data, vec![7; 9]);
+
+        m.set_row(1, &[1, 2, 3]);
+        m.set_column(2, &[0, 0, 0]);
+        assert_eq!(m.data, vec![7, 7, 0, 1, 2, 0, 7, 7, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column length must match matrix dimensions")]
+    fn test_set_column_length_mismatch_panics() {
+        let mut m = Matrix::<i32>::new(2, 2);
+        m.set_column(0, &[1, 2, 3]);
+    }
+
     #[test]
     fn test_row_arithmetic_chain() {
         let mut m: Matrix<i32> = Matrix::new(3, 2);
@@ -244,4 +618,19 @@ mod tests {
         assert_eq!(m[(0,0)], 14);
         assert_eq!(m[(0,1)], 19);
     }
+
+    #[test]
+    fn test_row_mut_enumerate_mut() {
+        let mut m = Matrix::<i32>::new(2, 4);
+        {
+            let mut r = m.row_mut(1);
+            for (j, v) in r.enumerate_mut() {
+                if j % 2 == 0 {
+                    *v = j as i32 + 1;
+                }
+            }
+        }
+        assert_eq!(m.row(1).data, vec![1, 0, 3, 0]);
+        assert_eq!(m.row(0).data, vec![0, 0, 0, 0]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,30 @@
+use num_traits::{One, Zero};
+use std::ops::{Div, Mul};
+
+/// Exact multiplicative inverse `1 / x`; panics on zero.
+pub fn reciprocal<T>(x: T) -> T
+where
+    T: Zero + One + Div<Output = T>,
+{
+    assert!(!x.is_zero(), "Cannot take the reciprocal of zero");
+    T::one() / x
+}
+
+/// `x^exp` by exponentiation-by-squaring; negative exponents use the
+/// reciprocal, so they panic on zero.
+pub fn powi<T>(x: T, exp: i32) -> T
+where
+    T: Zero + One + Copy + Mul<Output = T> + Div<Output = T>,
+{
+    let mut base = if exp < 0 { reciprocal(x) } else { x };
+    let mut e = exp.unsigned_abs();
+    let mut acc = T::one();
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = acc * base;
+        }
+        base = base * base;
+        e >>= 1;
+    }
+    acc
+}
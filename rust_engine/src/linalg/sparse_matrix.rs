@@ -0,0 +1,102 @@
+use crate::linalg::Matrix;
+use num_traits::Zero;
+
+/// Row-compressed sparse matrix: each row keeps its nonzero `(col, value)`
+/// entries sorted by column. Absent entries read as zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<T> {
+    pub rows: usize,
+    pub cols: usize,
+    entries: Vec<Vec<(usize, T)>>,
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: Clone + Zero,
+{
+    /// An all-zero `rows x cols` matrix.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        SparseMatrix {
+            rows,
+            cols,
+            entries: vec![Vec::new(); rows],
+        }
+    }
+
+    /// Builds from `(row, col, value)` triplets; later duplicates overwrite earlier ones.
+    pub fn from_triplets(rows: usize, cols: usize, triplets: impl IntoIterator<Item = (usize, usize, T)>) -> Self {
+        let mut m = Self::new(rows, cols);
+        for (r, c, v) in triplets {
+            m.set(r, c, v);
+        }
+        m
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> T {
+        self.check_bounds(r, c);
+        match self.entries[r].binary_search_by_key(&c, |(col, _)| *col) {
+            Ok(pos) => self.entries[r][pos].1.clone(),
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Stores `value` at `(r, c)`; storing zero removes the entry.
+    pub fn set(&mut self, r: usize, c: usize, value: T) {
+        self.check_bounds(r, c);
+        let row = &mut self.entries[r];
+        match row.binary_search_by_key(&c, |(col, _)| *col) {
+            Ok(pos) if value.is_zero() => {
+                row.remove(pos);
+            }
+            Ok(pos) => row[pos].1 = value,
+            Err(_) if value.is_zero() => {}
+            Err(pos) => row.insert(pos, (c, value)),
+        }
+    }
+
+    /// Number of stored nonzero entries.
+    pub fn nnz(&self) -> usize {
+        self.entries.iter().map(Vec::len).sum()
+    }
+
+    /// Nonzero `(col, value)` entries of row `r`, in column order.
+    pub fn row(&self, r: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        assert!(r < self.rows, "Row index {} out of range", r);
+        self.entries[r].iter().map(|(c, v)| (*c, v))
+    }
+
+    /// Rows in order, each as an iterator over its nonzero entries.
+    pub fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, &T)> + '_> + '_ {
+        (0..self.rows).map(move |r| self.row(r))
+    }
+
+    pub fn from_dense(dense: &Matrix<T>) -> Self {
+        let mut m = Self::new(dense.rows, dense.cols);
+        for (r, row) in m.entries.iter_mut().enumerate() {
+            for c in 0..dense.cols {
+                let v = &dense[(r, c)];
+                if !v.is_zero() {
+                    row.push((c, v.clone()));
+                }
+            }
+        }
+        m
+    }
+
+    pub fn to_dense(&self) -> Matrix<T>
+    where
+        T: Default,
+    {
+        let mut dense = Matrix::new(self.rows, self.cols);
+        for (r, row) in self.entries.iter().enumerate() {
+            for (c, v) in row {
+                dense[(r, *c)] = v.clone();
+            }
+        }
+        dense
+    }
+
+    fn check_bounds(&self, r: usize, c: usize) {
+        assert!(r < self.rows && c < self.cols, "Index ({}, {}) out of range for {}x{} matrix", r, c, self.rows, self.cols);
+    }
+}
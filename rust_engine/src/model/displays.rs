@@ -2,27 +2,69 @@ use std::fmt;
 use num_rational::Rational64;
 use num_traits::{Zero, Signed};
 
+use crate::linalg::rational_to_f64;
 use crate::model::Goal;
-use crate::model::problem::{Problem, Relation};
+use crate::model::standard_form::StandardForm;
+use crate::model::problem::{Problem, Relation, Sign};
 use crate::model::tableau_form::Tableau;
 
-fn format_rational(r: Rational64) -> String {
+/// How rationals are printed by `format_rational_as` and the `Display` impls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RationalFormat {
+    /// `7/3`
+    #[default]
+    Improper,
+    /// `2 1/3`
+    Mixed,
+    /// `2.333` with the given number of decimal places (approximate).
+    Decimal(usize),
+}
+
+impl RationalFormat {
+    /// Mode requested through formatter flags: `{:.N}` selects `Decimal(N)`,
+    /// `{:#}` selects `Mixed`, anything else `Improper`.
+    fn from_formatter(f: &fmt::Formatter<'_>) -> Self {
+        match f.precision() {
+            Some(p) => RationalFormat::Decimal(p),
+            None if f.alternate() => RationalFormat::Mixed,
+            None => RationalFormat::Improper,
+        }
+    }
+}
+
+/// Formats `r` in the given mode; integers never show a fraction part.
+pub fn format_rational_as(r: Rational64, mode: RationalFormat) -> String {
     if *r.denom() == 1 {
-        format!("{}", r.numer())
-    } else {
-        format!("{}/{}", r.numer(), r.denom())
+        if let RationalFormat::Decimal(p) = mode {
+            return format!("{:.*}", p, rational_to_f64(r));
+        }
+        return format!("{}", r.numer());
+    }
+    match mode {
+        RationalFormat::Improper => format!("{}/{}", r.numer(), r.denom()),
+        RationalFormat::Mixed => {
+            let whole = r.trunc();
+            let frac = (r - whole).abs();
+            if whole.is_zero() {
+                format!("{}/{}", r.numer(), r.denom())
+            } else {
+                format!("{} {}/{}", whole.numer(), frac.numer(), frac.denom())
+            }
+        }
+        RationalFormat::Decimal(p) => format!("{:.*}", p, rational_to_f64(r)),
     }
 }
 
 impl fmt::Display for Problem<Rational64> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = RationalFormat::from_formatter(f);
         let goal_str = match self.goal {
             Goal::Max => "Max",
             Goal::Min => "Min",
         };
 
         write!(f, "{} Z = ", goal_str)?;
-        writeln!(f, "{}", format_expression(&self.objective))?;
+        writeln!(f, "{}", format_expression(&self.objective, mode))?;
 
         writeln!(f, "\nSubject to:")?;
         for c in &self.constraints {
@@ -31,15 +73,59 @@ impl fmt::Display for Problem<Rational64> {
                 Relation::GreaterEqual => ">=",
                 Relation::Equal => "=",
             };
-            writeln!(f, "  {} {} {}", format_expression(&c.coefficients), rel, format_rational(c.rhs))?;
+            let expr = format_expression(&c.coefficients(), mode);
+            match (c.range, &c.relation) {
+                (Some(r), Relation::LessEqual) => {
+                    writeln!(f, "  {} <= {} <= {}", format_rational_as(c.rhs - r, mode), expr, format_rational_as(c.rhs, mode))?
+                }
+                (Some(r), Relation::GreaterEqual) => {
+                    writeln!(f, "  {} <= {} <= {}", format_rational_as(c.rhs, mode), expr, format_rational_as(c.rhs + r, mode))?
+                }
+                _ => writeln!(f, "  {} {} {}", expr, rel, format_rational_as(c.rhs, mode))?,
+            }
+        }
+        if self.variable_signs.iter().all(|&s| s == Sign::NonNegative) {
+            let vars: Vec<String> = (0..self.objective.len()).map(|i| format!("x{}", i)).collect();
+            writeln!(f, "  where  {}, ... >= 0", vars.join(", "))?;
+        } else {
+            let vars: Vec<String> = self
+                .variable_signs
+                .iter()
+                .enumerate()
+                .map(|(i, sign)| match sign {
+                    Sign::NonNegative => format!("x{} >= 0", i),
+                    Sign::NonPositive => format!("x{} <= 0", i),
+                    Sign::Free => format!("x{} free", i),
+                })
+                .collect();
+            writeln!(f, "  where  {}", vars.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for StandardForm<Rational64> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = RationalFormat::from_formatter(f);
+        let goal_str = match self.goal {
+            Goal::Max => "Max",
+            Goal::Min => "Min",
+        };
+
+        writeln!(f, "Min Z = {}  (original goal: {})", format_expression(&self.c, mode), goal_str)?;
+        writeln!(f, "\nSubject to:")?;
+        for i in 0..self.a.rows {
+            let row: Vec<Rational64> = (0..self.a.cols).map(|j| self.a[(i, j)]).collect();
+            writeln!(f, "  {} = {}", format_expression(&row, mode), format_rational_as(self.b[i], mode))?;
         }
-        let vars: Vec<String> = (0..self.objective.len()).map(|i| format!("x{}", i)).collect();
-        writeln!(f, "  where  {}, ... >= 0", vars.join(", "))?;
+        let slacks: Vec<String> = self.slack_indices.iter().map(|j| format!("x{}", j)).collect();
+        writeln!(f, "  slack columns: {}", slacks.join(", "))?;
+        writeln!(f, "  where  all x >= 0")?;
         Ok(())
     }
 }
 
-fn format_expression(coeffs: &[Rational64]) -> String {
+fn format_expression(coeffs: &[Rational64], mode: RationalFormat) -> String {
     let mut parts = Vec::new();
     for (i, &coeff) in coeffs.iter().enumerate() {
         if coeff.is_zero() { continue; }
@@ -48,7 +134,8 @@ fn format_expression(coeffs: &[Rational64]) -> String {
         let term = if abs_c.is_integer() && *abs_c.numer() == 1 {
             format!("x{}", i)
         } else {
-            format!("{}x{}", format_rational(abs_c), i)
+            let c = format_rational_as(abs_c, mode);
+            if c.contains(' ') { format!("({})x{}", c, i) } else { format!("{}x{}", c, i) }
         };
 
         if parts.is_empty() {
@@ -62,33 +149,33 @@ fn format_expression(coeffs: &[Rational64]) -> String {
 
 impl fmt::Display for Tableau<Rational64> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let n = self.n;
+        let mode = RationalFormat::from_formatter(f);
+        let n = self.num_structural_vars();
         let m = self.m;
         let rule_len = 10 + (n * 9) + (m * 9) + 10;
+        let labels = self.column_labels(n);
 
         writeln!(f, "\nTableau (Basis: {:?})", self.basis)?;
         write!(f, "{:>6} | ", "Basis")?;
-        for j in 0..n { write!(f, "x{:<7} ", j)?; }
+        for label in &labels[..n] { write!(f, "{:<8} ", label)?; }
         write!(f, "| ")?;
-        for j in 0..m { write!(f, "s{:<7} ", j)?; }
-        writeln!(f, "| {:>8}", "RHS")?;
+        for label in &labels[n..n + m] { write!(f, "{:<8} ", label)?; }
+        writeln!(f, "| {:>8}", labels[n + m])?;
         writeln!(f, "{}", "-".repeat(rule_len))?;
 
         for i in 0..m {
-            let label = if self.basis[i] < n { format!("x{}", self.basis[i]) }
-                        else { format!("s{}", self.basis[i] - n) };
-            write!(f, "{:>6} | ", label)?;
-            for j in 0..n { write!(f, "{:>8} ", format_rational(self[(i, j)]))?; }
+            write!(f, "{:>6} | ", labels[self.basis[i]])?;
+            for j in 0..n { write!(f, "{:>8} ", format_rational_as(self[(i, j)], mode))?; }
             write!(f, "| ")?;
-            for j in 0..m { write!(f, "{:>8} ", format_rational(self[(i, n + j)]))?; }
-            writeln!(f, "| {:>8}", format_rational(self.rhs(i)))?;
+            for j in 0..m { write!(f, "{:>8} ", format_rational_as(self[(i, n + j)], mode))?; }
+            writeln!(f, "| {:>8}", format_rational_as(self.rhs(i), mode))?;
         }
 
         writeln!(f, "{}", "-".repeat(rule_len))?;
         write!(f, "{:>6} | ", "Z")?;
-        for j in 0..n { write!(f, "{:>8} ", format_rational(self[(m, j)]))?; }
+        for j in 0..n { write!(f, "{:>8} ", format_rational_as(self[(m, j)], mode))?; }
         write!(f, "| ")?;
-        for j in 0..m { write!(f, "{:>8} ", format_rational(self[(m, n + j)]))?; }
-        writeln!(f, "| {:>8}", format_rational(self.z_rhs()))
+        for j in 0..m { write!(f, "{:>8} ", format_rational_as(self[(m, n + j)], mode))?; }
+        writeln!(f, "| {:>8}", format_rational_as(self.z_rhs(), mode))
     }
 }
@@ -4,16 +4,43 @@ pub mod tableau_form;
 pub mod tableau_operations;
 pub mod displays;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Goal {
     Min,
     Max,
 }
 
-pub use problem::{Problem, Relation, Constraint};
+impl std::str::FromStr for Goal {
+    type Err = String;
+
+    /// Accepts `max`/`maximize`/`maximise` and `min`/`minimize`/`minimise`,
+    /// case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "max" | "maximize" | "maximise" => Ok(Goal::Max),
+            "min" | "minimize" | "minimise" => Ok(Goal::Min),
+            _ => Err(format!("Unknown goal '{}'; use 'max' or 'min'", s)),
+        }
+    }
+}
+
+impl Goal {
+    /// Internally every objective is minimised; maps a value of that
+    /// minimised objective back to this goal's own sense.
+    pub fn from_minimized<T: std::ops::Neg<Output = T>>(self, value: T) -> T {
+        match self {
+            Goal::Min => value,
+            Goal::Max => -value,
+        }
+    }
+}
+
+pub use problem::{Problem, Relation, Constraint, ConstraintBuilder, Sign};
 pub use standard_form::StandardForm;
 pub use tableau_form::Tableau;
-pub use tableau_operations::PivotResult;
+pub use tableau_operations::{PivotResult, PivotRule, TieBreak};
+pub use displays::{format_rational_as, RationalFormat};
 
 #[cfg(test)]
 mod tests {
@@ -327,4 +354,753 @@ mod tests {
         assert_eq!(vertex[1], Rational64::new(0, 1));
         assert!(!tab.is_optimal());
     }
+
+    #[test]
+    fn test_pivot_col_tie_break() {
+        let mut prob = Problem::new(vec![rational(2), rational(1), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1), rational(1)], Relation::LessEqual, rational(4));
+        let tab = prob.into_tableau_form();
+
+        assert_eq!(tab.find_pivot_col_most_negative(), Some(0));
+        assert_eq!(tab.find_pivot_col_most_negative_with(TieBreak::Lowest), Some(0));
+        assert_eq!(tab.find_pivot_col_most_negative_with(TieBreak::Highest), Some(2));
+        assert_eq!(tab.find_pivot_indices_with(TieBreak::Highest), PivotResult::Pivot(0, 2));
+    }
+
+    #[test]
+    fn test_to_dense_matrix() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        let mut tab = prob.into_tableau_form();
+        tab.pivot(1, 0);
+
+        let dense = tab.to_dense_matrix();
+        assert_eq!(dense.rows, tab.rows() + 1);
+        assert_eq!(dense.cols, tab.cols());
+        assert_eq!(dense[(dense.rows - 1, dense.cols - 1)], tab.z_rhs());
+    }
+
+    #[test]
+    fn test_objective_sense_normalized_shared_by_both_forms() {
+        for goal in [Goal::Max, Goal::Min] {
+            let mut prob = Problem::new(vec![rational(3), rational(-2)], goal);
+            prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+
+            let (canonical, negated) = prob.objective_sense_normalized();
+            assert_eq!(negated, goal == Goal::Max);
+            assert_eq!(goal.from_minimized(canonical[0]), rational(3));
+
+            let sf = prob.clone().into_standard_form();
+            let tab = prob.into_tableau_form();
+            assert_eq!(sf.c[..2], canonical[..]);
+            assert_eq!(tab.z_row_vars()[..2], canonical[..]);
+        }
+    }
+
+    #[test]
+    fn test_pivot_exact_check_rejects_zero_element() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(3));
+        let mut tab = prob.into_tableau_form();
+        let before = tab.data.data.clone();
+
+        let err = tab.pivot_exact_check(0, 0).unwrap_err();
+        assert_eq!(err, "cannot pivot on zero element at (0, 0)");
+        assert_eq!(tab.data.data, before);
+
+        assert!(tab.pivot_exact_check(0, 1).is_ok());
+        assert_eq!(tab.basis[0], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pivot on zero element at (0, 0)")]
+    fn test_pivot_on_zero_element_panics() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(3));
+        prob.into_tableau_form().pivot(0, 0);
+    }
+
+    #[test]
+    fn test_relation_from_str() {
+        for tok in ["<=", "le", "leq", "LEQ", " Le "] {
+            assert_eq!(tok.parse::<Relation>(), Ok(Relation::LessEqual));
+        }
+        for tok in [">=", "ge", "geq", "GEQ"] {
+            assert_eq!(tok.parse::<Relation>(), Ok(Relation::GreaterEqual));
+        }
+        for tok in ["=", "==", "eq", "EQ"] {
+            assert_eq!(tok.parse::<Relation>(), Ok(Relation::Equal));
+        }
+        let err = "<>".parse::<Relation>().unwrap_err();
+        assert!(err.contains("Unknown relation '<>'"));
+    }
+
+    #[test]
+    fn test_goal_from_str() {
+        for tok in ["max", "MAX", "maximize", "Maximise"] {
+            assert_eq!(tok.parse::<Goal>(), Ok(Goal::Max));
+        }
+        for tok in ["min", "minimize", "MINIMISE"] {
+            assert_eq!(tok.parse::<Goal>(), Ok(Goal::Min));
+        }
+        let err = "maximse".parse::<Goal>().unwrap_err();
+        assert!(err.contains("Unknown goal 'maximse'"));
+    }
+
+    #[test]
+    fn test_reduced_cost() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        let mut tab = prob.into_tableau_form();
+        assert_eq!(tab.reduced_cost(0), rational(-3));
+        assert_eq!(tab.reduced_cost(2), rational(0));
+
+        tab.pivot(1, 0);
+        assert_eq!(tab.reduced_cost(1), Rational64::new(-1, 2));
+        assert_eq!(tab.reduced_cost(3), Rational64::new(3, 2));
+    }
+
+    #[test]
+    fn test_constraint_matrix() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(2)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(-3), rational(0)], Relation::GreaterEqual, rational(-5));
+        prob.add_constraint(vec![rational(5), rational(6)], Relation::Equal, rational(7));
+
+        let a = prob.constraint_matrix();
+        assert_eq!((a.rows, a.cols), (3, 2));
+        for (r, c) in prob.constraints.iter().enumerate() {
+            assert_eq!(a.row(r).data, c.coefficients());
+        }
+    }
+
+    #[test]
+    fn test_feasible_region_vertices_2d() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+
+        let mut vertices = prob.feasible_region_vertices();
+        vertices.sort();
+        let expected: Vec<Vec<Rational64>> = [(0, 0), (0, 4), (1, 3)]
+            .iter()
+            .map(|&(x, y)| vec![rational(x), rational(y)])
+            .chain(std::iter::once(vec![Rational64::new(5, 2), rational(0)]))
+            .collect();
+        assert_eq!(vertices, expected);
+    }
+
+    fn custom_basis_parts() -> (Matrix<i32>, Matrix<i32>) {
+        let mut coefficients = Matrix::new(2, 2);
+        coefficients[(0, 0)] = 1; coefficients[(0, 1)] = 2;
+        coefficients[(1, 0)] = 0; coefficients[(1, 1)] = 3;
+
+        let mut slack = Matrix::new(2, 2);
+        slack[(0, 0)] = 1; slack[(0, 1)] = 0;
+        slack[(1, 0)] = 0; slack[(1, 1)] = 1;
+        (coefficients, slack)
+    }
+
+    #[test]
+    fn test_from_matrices_with_basis() {
+        let (coefficients, slack) = custom_basis_parts();
+        let tableau = Tableau::from_matrices_with_basis(
+            coefficients, slack, vec![4, 6], vec![0, -1], vec![2, 0], 8, vec![0, 3],
+        );
+
+        assert_eq!(tableau.basis, vec![0, 3]);
+        assert_eq!(tableau.nonbasis, vec![1, 2]);
+        assert_eq!(tableau[(0, 1)], 2);
+        assert_eq!(tableau[(1, 1)], 3);
+        assert_eq!(tableau.rhs(1), 6);
+        assert_eq!(tableau.z_rhs(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "Basis column 1 is not the unit vector for row 1")]
+    fn test_from_matrices_with_basis_rejects_non_unit_column() {
+        let (coefficients, slack) = custom_basis_parts();
+        Tableau::from_matrices_with_basis(
+            coefficients, slack, vec![4, 6], vec![0, 0], vec![0, 0], 0, vec![0, 1],
+        );
+    }
+
+    #[test]
+    fn test_sparse_constraint_matches_dense_row() {
+        let objective = vec![rational(1); 5];
+        let mut dense = Problem::new(objective.clone(), Goal::Max);
+        dense.add_constraint(
+            vec![rational(0), rational(2), rational(0), rational(0), rational(-1)],
+            Relation::LessEqual,
+            rational(3),
+        );
+        let mut sparse = Problem::new(objective, Goal::Max);
+        sparse.add_sparse_constraint(vec![(1, rational(2)), (4, rational(-1))], Relation::LessEqual, rational(3));
+
+        assert!(sparse.constraints[0].is_sparse());
+        assert_eq!(sparse.constraints[0].sparse_entries(), vec![(1, rational(2)), (4, rational(-1))]);
+        assert_eq!(sparse.constraints[0].coefficient(4), rational(-1));
+        assert_eq!(sparse.constraints[0].coefficient(2), rational(0));
+        assert_eq!(sparse.canonical_hash(), dense.canonical_hash());
+        assert_eq!(
+            sparse.into_tableau_form().row(0).data,
+            dense.into_tableau_form().row(0).data
+        );
+    }
+
+    #[test]
+    fn test_constraint_storage_toggles_between_dense_and_sparse() {
+        let dense = Constraint::new(vec![rational(0), rational(3), rational(0)], Relation::GreaterEqual, rational(1));
+        let mut c = dense.clone();
+        assert!(!c.is_sparse());
+        c.set_sparse(true);
+        assert!(c.is_sparse());
+        assert_eq!(c.num_vars(), 3);
+        assert_eq!(c.num_nonzeros(), 1);
+        assert_eq!(c.sparse_entries(), vec![(1, rational(3))]);
+        assert!(c.is_satisfied_by(&[rational(0), rational(1), rational(0)]));
+        c.set_sparse(false);
+        assert_eq!(c, dense);
+
+        let merged = Constraint::new_sparse(
+            3,
+            vec![(2, rational(1)), (0, rational(5)), (2, rational(4)), (1, rational(0))],
+            Relation::Equal,
+            rational(0),
+        );
+        assert_eq!(merged.sparse_entries(), vec![(0, rational(5)), (2, rational(4))]);
+        assert_eq!(merged.coefficients(), vec![rational(5), rational(0), rational(4)]);
+    }
+
+    #[test]
+    fn test_structural_and_slack_counts() {
+        let mut prob = Problem::new(vec![rational(1), rational(2), rational(3)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1), rational(0)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(0), rational(1), rational(1)], Relation::GreaterEqual, rational(1));
+        prob.add_constraint(vec![rational(1), rational(0), rational(1)], Relation::Equal, rational(2));
+        let tab = prob.into_tableau_form();
+
+        assert_eq!(tab.num_structural_vars(), 3);
+        assert_eq!(tab.num_slack_vars(), 3);
+        assert_eq!(tab.num_structural_vars() + tab.num_slack_vars(), tab.num_vars());
+        assert_eq!(tab.current_vertex(tab.num_structural_vars()).len(), 3);
+    }
+
+    fn solve_tableau(tab: &mut Tableau<Rational64>) -> PivotResult {
+        loop {
+            match tab.find_pivot_indices() {
+                PivotResult::Pivot(row, col) => tab.pivot(row, col),
+                other => return other,
+            }
+        }
+    }
+
+    fn sample_tableau() -> Tableau<Rational64> {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        prob.into_tableau_form()
+    }
+
+    #[test]
+    fn test_append_constraint_before_solving() {
+        let mut tab = sample_tableau();
+        tab.append_constraint(vec![rational(0), rational(1)], rational(1), rational(2));
+        assert_eq!((tab.m, tab.cols()), (3, 6));
+        assert_eq!(tab.basis, vec![2, 3, 4]);
+
+        assert_eq!(solve_tableau(&mut tab), PivotResult::Optimal);
+        assert_eq!(tab.z_rhs(), Rational64::new(17, 2));
+        assert_eq!(tab.current_vertex(2), vec![Rational64::new(3, 2), rational(2)]);
+    }
+
+    #[test]
+    fn test_append_constraint_to_solved_tableau() {
+        let mut tab = sample_tableau();
+        solve_tableau(&mut tab);
+        assert_eq!(tab.z_rhs(), rational(9));
+
+        // x + 2y <= 8 holds at (1, 3) with slack 1.
+        tab.append_constraint(vec![rational(1), rational(2)], rational(1), rational(8));
+        for (i, &b) in tab.basis.iter().enumerate() {
+            assert!((0..tab.m).all(|r| tab[(r, b)] == if r == i { rational(1) } else { rational(0) }));
+        }
+        assert_eq!(tab.rhs(2), rational(1));
+        assert_eq!(solve_tableau(&mut tab), PivotResult::Optimal);
+        assert_eq!(tab.z_rhs(), rational(9));
+
+        // x + y >= 5 cuts off the current vertex.
+        tab.append_constraint(vec![rational(1), rational(1)], rational(-1), rational(5));
+        assert!(tab.has_negative_rhs());
+    }
+
+    #[test]
+    fn test_clone_without_constraints() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+
+        let empty = prob.clone_without_constraints();
+        assert_eq!(empty.objective, prob.objective);
+        assert_eq!(empty.goal, Goal::Min);
+        assert_eq!(empty.constraints_iter().count(), 0);
+
+        let rhs: Vec<_> = prob.constraints_iter().map(|c| c.rhs).collect();
+        assert_eq!(rhs, vec![rational(4), rational(5)]);
+    }
+
+    #[test]
+    fn test_reduce_objective_gcd_rescales_optimum() {
+        use crate::solvers::{InitSource, SimplexSolver, Solver};
+
+        let mut prob = Problem::new(vec![rational(6), rational(4)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        let original = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+
+        let factor = prob.reduce_objective_gcd();
+        assert_eq!(factor, rational(2));
+        assert_eq!(prob.objective, vec![rational(3), rational(2)]);
+
+        let reduced = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(reduced.x, original.x);
+        assert_eq!(reduced.objective * factor, original.objective);
+        assert_eq!(prob.reduce_objective_gcd(), rational(1));
+    }
+
+    #[test]
+    fn test_basic_variable_values_places_slacks() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        let mut tab = prob.into_tableau_form();
+        assert_eq!(tab.basic_variable_values(), vec![rational(0), rational(0), rational(4), rational(5)]);
+
+        // x0 enters on row 1: x0 = 5/2, s0 = 4 - 5/2, s1 leaves.
+        tab.pivot(1, 0);
+        assert_eq!(
+            tab.basic_variable_values(),
+            vec![Rational64::new(5, 2), rational(0), Rational64::new(3, 2), rational(0)]
+        );
+    }
+
+    #[test]
+    fn test_equality_to_inequalities_keeps_optimum() {
+        use crate::solvers::{InitSource, SimplexSolver, Solver};
+
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::Equal, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+
+        let split = prob.equality_to_inequalities();
+        let relations: Vec<_> = split.constraints_iter().map(|c| c.relation.clone()).collect();
+        assert_eq!(relations, vec![Relation::LessEqual, Relation::GreaterEqual, Relation::LessEqual]);
+        assert_eq!(split.constraints[1].coefficients(), prob.constraints[0].coefficients());
+        assert_eq!(split.constraints[1].rhs, rational(4));
+
+        let a = SimplexSolver::new().solve(InitSource::Problem(prob)).unwrap();
+        let b = SimplexSolver::new().solve(InitSource::Problem(split)).unwrap();
+        assert_eq!(a.objective, rational(9));
+        assert_eq!(b.objective, a.objective);
+        assert_eq!(b.x, a.x);
+    }
+
+    #[test]
+    fn test_swap_basis() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(0)], Relation::LessEqual, rational(5));
+        let mut tab = prob.into_tableau_form();
+
+        // y has no entry in row 1, so it cannot replace s1.
+        assert!(tab.swap_basis(1, 1).is_err());
+        assert!(tab.swap_basis(2, 0).is_err());
+        assert_eq!(tab.basis, vec![2, 3]);
+
+        tab.swap_basis(0, 1).unwrap();
+        assert_eq!(tab.basis, vec![1, 3]);
+        assert_eq!(tab.current_vertex(2), vec![rational(0), rational(4)]);
+        assert_eq!(tab.reduced_cost(0), rational(-1));
+        assert_eq!(tab.z_rhs(), rational(8));
+    }
+
+    #[test]
+    fn test_clone_subtableau() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(2));
+        let mut tab = prob.into_tableau_form();
+        tab.pivot(2, 0); // x0 basic in row 2
+
+        let sub = tab.clone_subtableau(&[2, 0]);
+        assert_eq!((sub.m, sub.n, sub.cols()), (2, 2, 5));
+        assert_eq!(sub.basis, vec![0, 3]);
+        assert_eq!(sub.nonbasis, vec![1, 2]);
+        assert_eq!(sub.row(0).data, vec![rational(1), rational(0), rational(1), rational(0), rational(2)]);
+        assert_eq!(sub.row(1).data, vec![rational(0), rational(1), rational(-1), rational(1), rational(2)]);
+        assert_eq!(sub.z_rhs(), tab.z_rhs());
+        assert_eq!(sub.current_vertex(2), vec![rational(2), rational(0)]);
+    }
+
+    #[test]
+    fn test_variable_signs_substituted_in_tableau() {
+        use crate::solvers::{InitSource, SimplexSolver, Solver};
+
+        // min x0 + x1 with x0 <= 0, x0 >= -3, x1 >= 1.
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Min);
+        prob.set_variable_sign(0, Sign::NonPositive);
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::GreaterEqual, rational(-3));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::GreaterEqual, rational(1));
+        let sol = SimplexSolver::new().solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(sol.x, vec![rational(-3), rational(1)]);
+        assert_eq!(sol.objective, rational(-2));
+
+        // max x0 with x0 free and x0 <= -2: infeasible if x0 were non-negative.
+        let mut free = Problem::new(vec![rational(1)], Goal::Max);
+        free.set_variable_sign(0, Sign::Free);
+        free.add_constraint(vec![rational(1)], Relation::LessEqual, rational(-2));
+        assert_eq!(free.to_tableau().num_structural_vars(), 2);
+        let sol = SimplexSolver::new().solve(InitSource::Problem(free)).unwrap();
+        assert_eq!(sol.x, vec![rational(-2)]);
+        assert_eq!(sol.objective, rational(-2));
+    }
+
+    #[test]
+    fn test_gcd_reduce_constraints_keeps_optimum() {
+        use crate::solvers::{InitSource, SimplexSolver, Solver};
+
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(6), rational(6)], Relation::LessEqual, rational(24));
+        prob.add_constraint(vec![rational(-4), rational(-2)], Relation::GreaterEqual, rational(-10));
+        prob.add_constraint(vec![Rational64::new(2, 3), rational(0)], Relation::LessEqual, rational(7));
+        let original = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+
+        prob.gcd_reduce_constraints();
+        assert_eq!(prob.constraints[0].coefficients(), vec![rational(1), rational(1)]);
+        assert_eq!(prob.constraints[0].rhs, rational(4));
+        assert_eq!(prob.constraints[1].coefficients(), vec![rational(-2), rational(-1)]);
+        assert_eq!(prob.constraints[1].rhs, rational(-5));
+        assert_eq!(prob.constraints[2].rhs, rational(7));
+
+        let reduced = SimplexSolver::new().solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(reduced.objective, original.objective);
+        assert_eq!(reduced.x, original.x);
+    }
+
+    #[test]
+    fn test_format_rational_modes() {
+        let r = Rational64::new(7, 3);
+        assert_eq!(format_rational_as(r, RationalFormat::Improper), "7/3");
+        assert_eq!(format_rational_as(r, RationalFormat::Mixed), "2 1/3");
+        assert_eq!(format_rational_as(-r, RationalFormat::Mixed), "-2 1/3");
+        assert_eq!(format_rational_as(Rational64::new(1, 3), RationalFormat::Mixed), "1/3");
+        assert_eq!(format_rational_as(r, RationalFormat::Decimal(3)), "2.333");
+        assert_eq!(format_rational_as(rational(2), RationalFormat::Mixed), "2");
+
+        let mut prob = Problem::new(vec![r, rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, r);
+        assert!(format!("{}", prob).starts_with("Max Z = 7/3x0 + x1"));
+        assert!(format!("{:#}", prob).starts_with("Max Z = (2 1/3)x0 + x1"));
+        assert!(format!("{:.2}", prob).contains("x0 + x1 <= 2.33"));
+    }
+
+    #[test]
+    fn test_constraint_builder_produces_dense_row() {
+        let c = ConstraintBuilder::new(4).term(0, 2).term(2, -1).leq(5);
+        assert_eq!(c.coefficients(), vec![2, 0, -1, 0]);
+        assert_eq!(c.relation, Relation::LessEqual);
+        assert_eq!(c.rhs, 5);
+
+        let c = ConstraintBuilder::new(2).term(1, rational(1)).term(1, rational(2)).geq(rational(3));
+        assert_eq!(c.coefficients(), vec![rational(0), rational(3)]);
+        assert_eq!(c.relation, Relation::GreaterEqual);
+    }
+
+    #[test]
+    fn test_normalize_rhs_nonneg_negates_repairable_rows() {
+        // -x - y - s0 = -4 with s0 basic, and x + s1 = -1 with s1 basic.
+        let mut data = Matrix::new(3, 5);
+        data.set_row(0, &[rational(-1), rational(-1), rational(-1), rational(0), rational(-4)]);
+        data.set_row(1, &[rational(1), rational(0), rational(0), rational(1), rational(-1)]);
+        data.set_row(2, &[rational(-1), rational(-1), rational(0), rational(0), rational(0)]);
+        let mut tab = Tableau::new(data, 2, 2);
+
+        assert_eq!(tab.normalize_rhs_nonneg(), vec![1]);
+        assert_eq!(tab.row(0).data, vec![rational(1), rational(1), rational(1), rational(0), rational(4)]);
+        assert_eq!(tab.rhs(1), rational(-1));
+
+        tab.data[(1, 4)] = rational(1);
+        assert!(tab.normalize_rhs_nonneg().is_empty());
+        assert!(!tab.has_negative_rhs());
+    }
+
+    #[test]
+    fn test_constraints_using_lists_rows_with_nonzero_coefficient() {
+        let mut prob = Problem::new(vec![rational(1), rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(0), rational(2)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(0), rational(3), rational(0)], Relation::LessEqual, rational(5));
+        prob.add_constraint(vec![rational(-1), rational(1), rational(0)], Relation::GreaterEqual, rational(0));
+        assert_eq!(prob.constraints_using(0), vec![0, 2]);
+        assert_eq!(prob.constraints_using(1), vec![1, 2]);
+        assert_eq!(prob.constraints_using(2), vec![0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_problem_json_round_trip() {
+        let mut prob = Problem::new(vec![rational(3), Rational64::new(1, 2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(-1)], Relation::Equal, Rational64::new(5, 3));
+        prob.set_variable_sign(1, Sign::Free);
+
+        let json = serde_json::to_string(&prob).unwrap();
+        let back: Problem<Rational64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, prob);
+    }
+
+    #[test]
+    fn test_shift_variable_translates_optimum() {
+        // max 3x + 2y s.t. x + y <= 4, 2x + y <= 5, with x >= 1 written as x = x' + 1.
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        prob.shift_variable(0, rational(1));
+        assert_eq!(prob.constraints[0].rhs, rational(3));
+        assert_eq!(prob.constraints[1].rhs, rational(3));
+        assert_eq!(prob.objective_constant(), rational(3));
+
+        let mut tab = prob.clone().into_tableau_form();
+        assert_eq!(tab.z_rhs(), rational(3));
+        assert_eq!(solve_tableau(&mut tab), PivotResult::Optimal);
+        assert_eq!(tab.current_vertex(2), vec![rational(1), rational(3)]);
+        assert_eq!(Goal::Max.from_minimized(-tab.z_rhs()), rational(9));
+    }
+
+    #[test]
+    fn test_diff_after_pivot_touches_expected_cells() {
+        let before = sample_tableau();
+        let mut after = before.clone();
+        after.pivot(1, 0);
+
+        let cells = before.diff(&after);
+        let positions: Vec<(usize, usize)> = cells.iter().map(|&(r, c, _, _)| (r, c)).collect();
+        // Column 2 (row 0's basic slack) is unchanged; every other column moves in every row.
+        let expected: Vec<(usize, usize)> = (0..3).flat_map(|r| [0, 1, 3, 4].map(|c| (r, c))).collect();
+        assert_eq!(positions, expected);
+        assert!(cells.contains(&(1, 4, rational(5), Rational64::new(5, 2))));
+        assert!(cells.contains(&(2, 0, rational(-3), rational(0))));
+        assert!(before.diff(&before).is_empty());
+    }
+
+    #[test]
+    fn test_num_nonzeros_and_density_on_sparse_rows() {
+        let mut prob = Problem::new(vec![rational(1); 10], Goal::Max);
+        for i in 0..4 {
+            let mut row = vec![rational(0); 10];
+            row[i] = rational(1);
+            row[9 - i] = rational(2);
+            prob.add_constraint(row, Relation::LessEqual, rational(1));
+        }
+        assert_eq!(prob.num_nonzeros(), 8);
+        assert_eq!(prob.density(), 0.2);
+        assert_eq!(Problem::new(vec![rational(1)], Goal::Max).density(), 0.0);
+    }
+
+    #[test]
+    fn test_canonical_hash_ignores_row_order_and_tracks_coefficients() {
+        let mut a = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        a.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        a.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        let mut b = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        b.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        b.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        assert_eq!(a.canonical_hash(), b.canonical_hash());
+        assert_eq!(a.canonical_hash(), a.clone().canonical_hash());
+
+        b.constraints[0] = Constraint::new(vec![rational(2), rational(2)], Relation::LessEqual, rational(5));
+        assert_ne!(a.canonical_hash(), b.canonical_hash());
+        let mut c = a.clone();
+        c.goal = Goal::Min;
+        assert_ne!(a.canonical_hash(), c.canonical_hash());
+
+        let mut zero_offsets = a.clone();
+        zero_offsets.variable_offsets = vec![rational(0), rational(0)];
+        assert_eq!(a.canonical_hash(), zero_offsets.canonical_hash());
+        let mut lexicographic = a.clone();
+        lexicographic.set_lexicographic_objectives(vec![
+            (vec![rational(3), rational(2)], Goal::Max),
+            (vec![rational(1), rational(0)], Goal::Min),
+        ]);
+        assert_ne!(a.canonical_hash(), lexicographic.canonical_hash());
+    }
+
+    #[test]
+    fn test_improvement_of_entering_matches_pivot() {
+        let tab = sample_tableau();
+        let objective = |t: &Tableau<Rational64>| Goal::Max.from_minimized(-t.z_rhs());
+        for col in [0, 1] {
+            let gain = tab.improvement_of_entering(col).unwrap();
+            let mut after = tab.clone();
+            after.pivot(tab.ratio_test(col).unwrap(), col);
+            assert_eq!(objective(&after) - objective(&tab), gain);
+        }
+
+        // A basic column has zero reduced cost, so it cannot improve.
+        let mut after = tab.clone();
+        after.pivot(tab.ratio_test(0).unwrap(), 0);
+        assert_eq!(after.improvement_of_entering(0), None);
+    }
+
+    #[test]
+    fn test_feasible_region_vertices_respect_signs() {
+        // x0 free in [-5, -2], x1 <= 0 and x1 >= -1.
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.set_variable_sign(0, Sign::Free);
+        prob.set_variable_sign(1, Sign::NonPositive);
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(-2));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::GreaterEqual, rational(-5));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::GreaterEqual, rational(-1));
+
+        let mut vertices = prob.feasible_region_vertices();
+        vertices.sort();
+        assert_eq!(
+            vertices,
+            vec![
+                vec![rational(-5), rational(-1)],
+                vec![rational(-5), rational(0)],
+                vec![rational(-2), rational(-1)],
+                vec![rational(-2), rational(0)],
+            ]
+        );
+        assert!(format!("{}", prob).contains("where  x0 free, x1 <= 0"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Standard form requires non-negative variables")]
+    fn test_into_standard_form_rejects_sign_restrictions() {
+        let mut prob = Problem::new(vec![rational(1)], Goal::Max);
+        prob.set_variable_sign(0, Sign::Free);
+        prob.into_standard_form();
+    }
+
+    #[test]
+    #[should_panic(expected = "Standard form does not support shifted variables")]
+    fn test_into_standard_form_rejects_shifted_variables() {
+        let mut prob = Problem::new(vec![rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1)], Relation::LessEqual, rational(4));
+        prob.shift_variable(0, rational(1));
+        prob.into_standard_form();
+    }
+
+    #[test]
+    fn test_column_labels() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        for _ in 0..3 {
+            prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(1));
+        }
+        let tab = prob.into_tableau_form();
+        assert_eq!(tab.column_labels(2), vec!["x0", "x1", "s0", "s1", "s2", "RHS"]);
+
+        let shown = format!("{}", tab);
+        assert!(shown.contains("x1       | s0"));
+        assert!(shown.contains("    s2 | "));
+    }
+
+    #[test]
+    fn test_merge_problems() {
+        use crate::solvers::{InitSource, SimplexSolver, Solver, Status};
+
+        let mut a = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        a.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+
+        let mut b = Problem::new(vec![rational(0), rational(0)], Goal::Max);
+        b.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+
+        a.merge(&b);
+        assert_eq!(a.constraints.len(), 2);
+        assert_eq!(a.objective, vec![rational(3), rational(2)]);
+
+        let sol = SimplexSolver::new().solve(InitSource::Problem(a)).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.objective, rational(9));
+        assert_eq!(sol.x, vec![rational(1), rational(3)]);
+    }
+
+    #[test]
+    fn test_dual_of_max_problem() {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::GreaterEqual, rational(1));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::Equal, rational(2));
+
+        let dual = prob.dual();
+        assert_eq!(dual.goal, Goal::Min);
+        // One dual variable per canonical row; the equality contributes two.
+        assert_eq!(dual.objective, vec![rational(4), rational(-1), rational(2), rational(-2)]);
+        assert_eq!(dual.constraints.len(), 2);
+        assert_eq!(dual.constraints[0].relation, Relation::GreaterEqual);
+        assert_eq!(dual.constraints[0].coefficients(), vec![rational(1), rational(-1), rational(0), rational(0)]);
+        assert_eq!(dual.constraints[0].rhs, rational(3));
+        assert_eq!(dual.constraints[1].coefficients(), vec![rational(1), rational(0), rational(1), rational(-1)]);
+        assert_eq!(dual.constraints[1].rhs, rational(2));
+    }
+
+    #[test]
+    fn test_restore_feasible_basis_for_geq_row() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(2));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(5));
+
+        let mut tab = prob.into_tableau_form();
+        assert_eq!(tab.infeasible_basis_rows(), vec![0]);
+        assert!(tab.restore_feasible_basis());
+        assert!(tab.infeasible_basis_rows().is_empty());
+
+        let x = tab.current_vertex(2);
+        assert_eq!(x[0] + x[1], rational(2));
+    }
+
+    #[test]
+    fn test_restore_feasible_basis_detects_infeasible() {
+        let mut prob = Problem::new(vec![rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1)], Relation::LessEqual, rational(1));
+        prob.add_constraint(vec![rational(1)], Relation::GreaterEqual, rational(2));
+
+        let mut tab = prob.into_tableau_form();
+        assert!(!tab.restore_feasible_basis());
+    }
+
+    #[test]
+    fn test_fix_variable_forces_value() {
+        use crate::solvers::{InitSource, SimplexSolver, Solver, Status};
+
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+
+        let branch = prob.clone_with_bound(0, rational(2), rational(2));
+        assert_eq!(prob.constraints.len(), 2);
+        assert_eq!(branch.constraints.len(), 3);
+
+        let sol = SimplexSolver::new().solve(InitSource::Problem(branch)).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.x, vec![rational(2), rational(1)]);
+        assert_eq!(sol.objective, rational(8));
+
+        prob.fix_variable(1, rational(0), rational(2));
+        assert_eq!(prob.constraints.len(), 3);
+        assert_eq!(prob.constraints[2].relation, Relation::LessEqual);
+        assert_eq!(prob.constraints[2].range, Some(rational(2)));
+    }
+
+    #[test]
+    fn test_merge_with_objective_flips_opposite_goal() {
+        let mut a = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        let b = Problem::new(vec![rational(1), rational(1)], Goal::Min);
+        a.merge_with_objective(&b);
+        assert_eq!(a.objective, vec![rational(2), rational(1)]);
+    }
 }
@@ -1,21 +1,182 @@
 use super::Goal;
 use crate::model::{StandardForm, Tableau};
 use crate::linalg::Matrix;
-use std::ops::Neg;
-use num_traits::{One, Zero};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::str::FromStr;
+use num_rational::Rational64;
+use num_traits::{CheckedAdd, CheckedMul, One, Signed, Zero};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Sign restriction on a decision variable; variables are non-negative unless
+/// overridden with `Problem::set_variable_sign`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sign {
+    #[default]
+    NonNegative,
+    NonPositive,
+    Free,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Relation {
     LessEqual,
     GreaterEqual,
     Equal,
 }
 
-#[derive(Debug, Clone)]
+impl FromStr for Relation {
+    type Err = String;
+
+    /// Accepts `<=`, `>=`, `=`, `==` and the names `le`/`leq`, `ge`/`geq`,
+    /// `eq`, case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "<=" | "le" | "leq" => Ok(Relation::LessEqual),
+            ">=" | "ge" | "geq" => Ok(Relation::GreaterEqual),
+            "=" | "==" | "eq" => Ok(Relation::Equal),
+            _ => Err(format!("Unknown relation '{}'; use '<=', '>=', or '='", s)),
+        }
+    }
+}
+
+/// How a `Constraint` stores its coefficients.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Storage<T> {
+    /// One coefficient per variable.
+    Dense(Vec<T>),
+    /// Nonzero `(variable, coefficient)` entries in variable order, over
+    /// `n_vars` variables.
+    Sparse { n_vars: usize, entries: Vec<(usize, T)> },
+}
+
+/// One row `coefficients . x (relation) rhs`. Coefficients are stored densely
+/// unless the row was added sparsely or switched with `set_sparse`; a sparse
+/// row is only expanded when the tableau is built. Equality compares the
+/// storage as well as the values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constraint<T> {
-    pub coefficients: Vec<T>,
+    coefficients: Storage<T>,
     pub relation: Relation,
     pub rhs: T,
+    /// Upper bound on the row's slack, set by `Problem::add_range_constraint`:
+    /// a `<=` row then also requires `lhs >= rhs - range`, a `>=` row
+    /// `lhs <= rhs + range`.
+    pub range: Option<T>,
+}
+
+impl<T> Constraint<T> {
+    /// Dense row with no range.
+    pub fn new(coefficients: Vec<T>, relation: Relation, rhs: T) -> Self {
+        Constraint { coefficients: Storage::Dense(coefficients), relation, rhs, range: None }
+    }
+
+    /// Sparse row over `n_vars` variables from its `(variable, coefficient)`
+    /// entries; a variable given twice keeps its last coefficient, and zero
+    /// coefficients are dropped.
+    pub fn new_sparse(n_vars: usize, mut entries: Vec<(usize, T)>, relation: Relation, rhs: T) -> Self
+    where
+        T: Zero,
+    {
+        entries.sort_by_key(|&(j, _)| j);
+        let mut stored: Vec<(usize, T)> = Vec::with_capacity(entries.len());
+        for (j, v) in entries {
+            assert!(j < n_vars, "Variable index {} out of range for {} variables", j, n_vars);
+            match stored.last_mut() {
+                Some(last) if last.0 == j => last.1 = v,
+                _ => stored.push((j, v)),
+            }
+        }
+        stored.retain(|(_, v)| !v.is_zero());
+        Constraint { coefficients: Storage::Sparse { n_vars, entries: stored }, relation, rhs, range: None }
+    }
+
+    /// Number of variables the row ranges over.
+    pub fn num_vars(&self) -> usize {
+        match &self.coefficients {
+            Storage::Dense(values) => values.len(),
+            Storage::Sparse { n_vars, .. } => *n_vars,
+        }
+    }
+
+    /// Whether the coefficients are stored as sparse entries.
+    pub fn is_sparse(&self) -> bool {
+        matches!(self.coefficients, Storage::Sparse { .. })
+    }
+
+    /// Applies `f` to every stored coefficient; a sparse row's implicit
+    /// zeros are skipped, so `f` must map zero to zero.
+    fn map_stored(&mut self, f: impl FnMut(&mut T)) {
+        match &mut self.coefficients {
+            Storage::Dense(values) => values.iter_mut().for_each(f),
+            Storage::Sparse { entries, .. } => entries.iter_mut().map(|(_, v)| v).for_each(f),
+        }
+    }
+}
+
+impl<T> Constraint<T>
+where
+    T: Clone + Zero,
+{
+    /// Coefficient of `x[var]`.
+    pub fn coefficient(&self, var: usize) -> T {
+        assert!(var < self.num_vars(), "Variable index {} out of range", var);
+        match &self.coefficients {
+            Storage::Dense(values) => values[var].clone(),
+            Storage::Sparse { entries, .. } => entries
+                .binary_search_by_key(&var, |&(j, _)| j)
+                .map_or_else(|_| T::zero(), |k| entries[k].1.clone()),
+        }
+    }
+
+    /// All coefficients, one per variable, expanding a sparse row.
+    pub fn coefficients(&self) -> Vec<T> {
+        match &self.coefficients {
+            Storage::Dense(values) => values.clone(),
+            Storage::Sparse { n_vars, entries } => {
+                let mut values = vec![T::zero(); *n_vars];
+                for (j, v) in entries {
+                    values[*j] = v.clone();
+                }
+                values
+            }
+        }
+    }
+
+    /// Nonzero `(variable, coefficient)` entries of this row, in variable order.
+    pub fn sparse_entries(&self) -> Vec<(usize, T)> {
+        match &self.coefficients {
+            Storage::Dense(values) => values
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| !v.is_zero())
+                .map(|(j, v)| (j, v.clone()))
+                .collect(),
+            Storage::Sparse { entries, .. } => entries.clone(),
+        }
+    }
+
+    /// Number of nonzero coefficients.
+    pub fn num_nonzeros(&self) -> usize {
+        match &self.coefficients {
+            Storage::Dense(values) => values.iter().filter(|v| !v.is_zero()).count(),
+            Storage::Sparse { entries, .. } => entries.len(),
+        }
+    }
+
+    /// Switches the row to sparse (`true`) or dense (`false`) storage.
+    pub fn set_sparse(&mut self, sparse: bool) {
+        if sparse != self.is_sparse() {
+            self.coefficients = if sparse {
+                Storage::Sparse { n_vars: self.num_vars(), entries: self.sparse_entries() }
+            } else {
+                Storage::Dense(self.coefficients())
+            };
+        }
+    }
 }
 
 impl<T> Constraint<T>
@@ -24,7 +185,7 @@ where
 {
     pub fn normalise(mut self) -> Self {
         if self.rhs < T::default() {
-            self.coefficients.iter_mut().for_each(|v| *v = -*v);
+            self.map_stored(|v| *v = -*v);
             self.rhs = -self.rhs;
             self.relation = match self.relation {
                 Relation::LessEqual => Relation::GreaterEqual,
@@ -36,28 +197,654 @@ where
     }
 }
 
+impl<T> Constraint<T>
+where
+    T: Copy + PartialOrd + Zero + Mul<Output = T>,
+{
+    /// Whether the point `x` satisfies this constraint exactly.
+    pub fn is_satisfied_by(&self, x: &[T]) -> bool {
+        assert_eq!(x.len(), self.num_vars(), "Point length must match number of coefficients");
+        let lhs = self.sparse_entries().into_iter().fold(T::zero(), |acc, (j, a)| acc + a * x[j]);
+        match self.relation {
+            Relation::LessEqual => lhs <= self.rhs && self.range.is_none_or(|r| lhs + r >= self.rhs),
+            Relation::GreaterEqual => lhs >= self.rhs && self.range.is_none_or(|r| lhs <= self.rhs + r),
+            Relation::Equal => lhs == self.rhs,
+        }
+    }
+}
+
+/// Builds a dense `Constraint` term by term, e.g.
+/// `ConstraintBuilder::new(3).term(0, 2).term(2, -1).leq(5)` for `2*x0 - x2 <= 5`.
 #[derive(Debug, Clone)]
+pub struct ConstraintBuilder<T> {
+    coefficients: Vec<T>,
+}
+
+impl<T> ConstraintBuilder<T>
+where
+    T: Copy + Zero,
+{
+    /// Starts an all-zero row over `n_vars` variables.
+    pub fn new(n_vars: usize) -> Self {
+        Self { coefficients: vec![T::zero(); n_vars] }
+    }
+
+    /// Adds `coef * x[var]`; repeated terms on the same variable accumulate.
+    pub fn term(mut self, var: usize, coef: T) -> Self {
+        assert!(var < self.coefficients.len(), "Variable index {} out of range", var);
+        self.coefficients[var] = self.coefficients[var] + coef;
+        self
+    }
+
+    pub fn leq(self, rhs: T) -> Constraint<T> {
+        self.finish(Relation::LessEqual, rhs)
+    }
+
+    pub fn geq(self, rhs: T) -> Constraint<T> {
+        self.finish(Relation::GreaterEqual, rhs)
+    }
+
+    pub fn eq(self, rhs: T) -> Constraint<T> {
+        self.finish(Relation::Equal, rhs)
+    }
+
+    fn finish(self, relation: Relation, rhs: T) -> Constraint<T> {
+        Constraint::new(self.coefficients, relation, rhs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Problem<T> {
     pub constraints: Vec<Constraint<T>>,
     pub objective: Vec<T>,
     pub goal: Goal,
+    /// Prioritised objectives for goal programming, highest priority first;
+    /// empty for an ordinary LP. See `solvers::solve_lexicographic`.
+    pub lexicographic_objectives: Vec<(Vec<T>, Goal)>,
+    /// Sign restriction of each variable, applied during tableau construction.
+    pub variable_signs: Vec<Sign>,
+    /// Offsets from `shift_variable`: `x[j] = y[j] + variable_offsets[j]`.
+    /// Empty when no variable is shifted.
+    pub variable_offsets: Vec<T>,
 }
 
 impl<T> Problem<T> {
     pub fn new(objective: Vec<T>, goal: Goal) -> Self {
         Self {
+            variable_signs: vec![Sign::NonNegative; objective.len()],
             objective,
             goal,
             constraints: Vec::new(),
+            lexicographic_objectives: Vec::new(),
+            variable_offsets: Vec::new(),
         }
     }
 
+    /// Restricts `x[var]` to be non-negative, non-positive or free.
+    pub fn set_variable_sign(&mut self, var: usize, sign: Sign) {
+        assert!(var < self.variable_signs.len(), "Variable index {} out of range", var);
+        self.variable_signs[var] = sign;
+    }
+
     pub fn add_constraint(&mut self, coefficients: Vec<T>, relation: Relation, rhs: T) {
-        self.constraints.push(Constraint {
-            coefficients,
-            relation,
-            rhs,
-        });
+        self.constraints.push(Constraint::new(coefficients, relation, rhs));
+    }
+
+    /// Adds a constraint given only its nonzero `(variable, coefficient)`
+    /// entries, stored sparsely (see `Constraint::new_sparse`).
+    pub fn add_sparse_constraint(&mut self, entries: Vec<(usize, T)>, relation: Relation, rhs: T)
+    where
+        T: Zero,
+    {
+        let n = self.objective.len();
+        self.constraints.push(Constraint::new_sparse(n, entries, relation, rhs));
+    }
+
+    /// Whether `shift_variable` has moved any variable by a nonzero offset.
+    pub fn is_shifted(&self) -> bool
+    where
+        T: Zero,
+    {
+        self.variable_offsets.iter().any(|o| !o.is_zero())
+    }
+
+    /// Checks that every constraint has one coefficient per objective variable,
+    /// naming the first offending constraint otherwise.
+    pub fn dimensions_consistent(&self) -> Result<(), String> {
+        let n = self.objective.len();
+        match self.constraints.iter().position(|c| c.num_vars() != n) {
+            Some(i) => Err(format!(
+                "Constraint {} has {} coefficients; expected {}",
+                i,
+                self.constraints[i].num_vars(),
+                n
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Iterates the constraints in insertion order.
+    pub fn constraints_iter(&self) -> std::slice::Iter<'_, Constraint<T>> {
+        self.constraints.iter()
+    }
+
+    /// Removes and returns the constraint at `index`; later constraints shift down.
+    pub fn remove_constraint(&mut self, index: usize) -> Constraint<T> {
+        assert!(
+            index < self.constraints.len(),
+            "Constraint index {} out of range for {} constraints",
+            index,
+            self.constraints.len()
+        );
+        self.constraints.remove(index)
+    }
+}
+
+/// Rewrites a row over the original variables into tableau columns:
+/// `x = -x'` for non-positive variables, and `x = x+ - x-` for free ones
+/// with each `x-` column appended after the original variables. Variables
+/// past the end of `signs` are non-negative.
+pub(crate) fn substitute_signs<T>(signs: &[Sign], coeffs: Vec<T>) -> Vec<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    let mut negative_parts = Vec::new();
+    let mut row: Vec<T> = coeffs
+        .into_iter()
+        .enumerate()
+        .map(|(j, a)| match signs.get(j).copied().unwrap_or_default() {
+            Sign::NonNegative => a,
+            Sign::NonPositive => -a,
+            Sign::Free => {
+                negative_parts.push(-a.clone());
+                a
+            }
+        })
+        .collect();
+    row.extend(negative_parts);
+    row
+}
+
+impl<T> Problem<T>
+where
+    T: Clone + Add<Output = T> + Neg<Output = T>,
+{
+    /// Replaces each slack bound from `add_range_constraint` by the opposing
+    /// one-sided row, appended after the original rows so constraint `i`
+    /// keeps row (and slack column) `i`. Tableaux keep the bounded slack
+    /// instead; this form is for standard form, the dual and the other
+    /// paths that only know one-sided rows.
+    fn split_ranges(&mut self) {
+        let mut bound_rows = Vec::new();
+        for c in &mut self.constraints {
+            let Some(range) = c.range.take() else { continue };
+            let (relation, rhs) = match c.relation {
+                Relation::LessEqual => (Relation::GreaterEqual, c.rhs.clone() + -range),
+                Relation::GreaterEqual => (Relation::LessEqual, c.rhs.clone() + range),
+                Relation::Equal => continue,
+            };
+            bound_rows.push(Constraint { coefficients: c.coefficients.clone(), relation, rhs, range: None });
+        }
+        self.constraints.extend(bound_rows);
+    }
+}
+
+impl<T: Clone> Problem<T> {
+    /// Copy of this problem with the same objective and goal but no constraints.
+    pub fn clone_without_constraints(&self) -> Problem<T> {
+        let mut problem = Problem::new(self.objective.clone(), self.goal);
+        problem.lexicographic_objectives = self.lexicographic_objectives.clone();
+        problem.variable_signs = self.variable_signs.clone();
+        problem.variable_offsets = self.variable_offsets.clone();
+        problem
+    }
+
+    /// Sets prioritised objectives, highest priority first. The first one also
+    /// becomes `objective`/`goal`, so ordinary solvers optimise the primary.
+    pub fn set_lexicographic_objectives(&mut self, objs: Vec<(Vec<T>, Goal)>) {
+        assert!(!objs.is_empty(), "At least one objective is required");
+        for (obj, _) in &objs {
+            assert_eq!(obj.len(), self.objective.len(), "Objective length must match number of variables");
+        }
+        self.objective = objs[0].0.clone();
+        self.goal = objs[0].1;
+        self.lexicographic_objectives = objs;
+    }
+
+    /// Copy with each `a.x = b` replaced, in place, by `a.x <= b` followed by
+    /// `a.x >= b`, for code paths that cannot handle equality rows.
+    pub fn equality_to_inequalities(&self) -> Problem<T> {
+        let mut out = self.clone_without_constraints();
+        for c in &self.constraints {
+            if c.relation == Relation::Equal {
+                out.constraints.push(Constraint { relation: Relation::LessEqual, ..c.clone() });
+                out.constraints.push(Constraint { relation: Relation::GreaterEqual, ..c.clone() });
+            } else {
+                out.constraints.push(c.clone());
+            }
+        }
+        out
+    }
+
+    /// Raw `m x n` coefficient matrix of the constraints as entered,
+    /// without slack columns or sign normalisation.
+    pub fn constraint_matrix(&self) -> Matrix<T>
+    where
+        T: Zero,
+    {
+        let cols = self.objective.len();
+        let data: Vec<T> = self
+            .constraints
+            .iter()
+            .flat_map(|c| {
+                assert_eq!(c.num_vars(), cols, "Constraint length must match number of variables");
+                c.coefficients()
+            })
+            .collect();
+        Matrix { rows: self.constraints.len(), cols, data }
+    }
+
+    /// Adds one constraint per row of `a`, all with `relation`, against `rhs`.
+    /// Pairs with `Matrix::from_diagonal_blocks` for block-structured rows.
+    pub fn add_constraint_rows(&mut self, a: &Matrix<T>, relation: Relation, rhs: &[T]) {
+        assert_eq!(a.cols, self.objective.len(), "Matrix columns must match number of variables");
+        assert_eq!(a.rows, rhs.len(), "Right-hand side length must match matrix rows");
+        for (row, b) in a.iter_rows().zip(rhs) {
+            self.add_constraint(row.to_vec(), relation.clone(), b.clone());
+        }
+    }
+
+    /// Appends `other`'s constraints; both problems must share the same variables.
+    /// The objective of `self` is kept unchanged.
+    pub fn merge(&mut self, other: &Problem<T>) {
+        assert_eq!(
+            self.objective.len(),
+            other.objective.len(),
+            "Merged problems must have the same number of variables"
+        );
+        self.constraints.extend(other.constraints.iter().cloned());
+    }
+
+    /// Like `merge`, but also adds `other`'s objective into `self`'s,
+    /// negating it first when the two goals differ.
+    pub fn merge_with_objective(&mut self, other: &Problem<T>)
+    where
+        T: std::ops::Add<Output = T> + Neg<Output = T>,
+    {
+        self.merge(other);
+        for (c, o) in self.objective.iter_mut().zip(&other.objective) {
+            let o = if other.goal == self.goal { o.clone() } else { -o.clone() };
+            *c = c.clone() + o;
+        }
+    }
+}
+
+impl<T> Problem<T>
+where
+    T: Clone + PartialOrd + Zero + One + Sub<Output = T>,
+{
+    /// Indices of the constraints with a nonzero coefficient on `x[var]`.
+    pub fn constraints_using(&self, var: usize) -> Vec<usize> {
+        assert!(var < self.objective.len(), "Variable index {} out of range", var);
+        self.constraints
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| var < c.num_vars() && !c.coefficient(var).is_zero())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Number of nonzero coefficients in the constraint matrix.
+    pub fn num_nonzeros(&self) -> usize {
+        self.constraints
+            .iter()
+            .map(|c| c.num_nonzeros())
+            .sum()
+    }
+
+    /// Fraction of constraint-matrix entries that are nonzero; 0 for an empty matrix.
+    pub fn density(&self) -> f64 {
+        let cells = self.constraints.len() * self.objective.len();
+        if cells == 0 {
+            return 0.0;
+        }
+        self.num_nonzeros() as f64 / cells as f64
+    }
+
+    /// Restricts `x[var]` to `[lo, hi]` by appending a bound row, as a
+    /// branch-and-bound driver does when branching. `lo == hi` adds an
+    /// equality row.
+    pub fn fix_variable(&mut self, var: usize, lo: T, hi: T) {
+        let n = self.objective.len();
+        assert!(var < n, "Variable index {} out of range for {} variables", var, n);
+
+        let mut unit = vec![T::zero(); n];
+        unit[var] = T::one();
+        self.add_range_constraint(unit, lo, hi);
+    }
+
+    /// Adds `lower <= coeffs . x <= upper` as a single `<=` row whose slack is
+    /// bounded by `upper - lower` (an `=` row when the bounds coincide).
+    pub fn add_range_constraint(&mut self, coeffs: Vec<T>, lower: T, upper: T) {
+        assert!(lower <= upper, "Lower bound must not exceed upper bound");
+        if lower == upper {
+            self.add_constraint(coeffs, Relation::Equal, lower);
+        } else {
+            let range = upper.clone() - lower;
+            self.add_constraint(coeffs, Relation::LessEqual, upper);
+            self.constraints.last_mut().unwrap().range = Some(range);
+        }
+    }
+
+    /// Returns a copy of this problem with `x[var]` restricted to `[lo, hi]`.
+    pub fn clone_with_bound(&self, var: usize, lo: T, hi: T) -> Problem<T> {
+        let mut branch = self.clone();
+        branch.fix_variable(var, lo, hi);
+        branch
+    }
+}
+
+impl<T> Problem<T>
+where
+    T: Copy + PartialOrd + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Substitutes `x[var] = y + offset`, e.g. turning a lower bound
+    /// `x[var] >= offset` into `y >= 0`. Constraint right-hand sides absorb
+    /// `a * offset`; the sign restriction of `var` then applies to `y`. Tableau
+    /// solvers add `objective_constant()` back and report the original `x`.
+    pub fn shift_variable(&mut self, var: usize, offset: T) {
+        let n = self.objective.len();
+        assert!(var < n, "Variable index {} out of range for {} variables", var, n);
+        for c in &mut self.constraints {
+            c.rhs = c.rhs - c.coefficient(var) * offset;
+        }
+        self.variable_offsets.resize(n, T::zero());
+        self.variable_offsets[var] = self.variable_offsets[var] + offset;
+    }
+
+    /// Constant `sum_j c_j * offset_j` that shifting adds to the objective.
+    pub fn objective_constant(&self) -> T {
+        self.objective
+            .iter()
+            .zip(&self.variable_offsets)
+            .fold(T::zero(), |acc, (&c, &o)| acc + c * o)
+    }
+
+    /// Vertices of the feasible region (constraints plus variable signs),
+    /// found by intersecting every `n`-subset of constraint and `x_j = 0`
+    /// hyperplanes (for sign-restricted `x_j`) and keeping the feasible points. Exponential in size, so limited to
+    /// problems with at most 3 variables.
+    pub fn feasible_region_vertices(&self) -> Vec<Vec<T>>
+    where
+        T: Neg<Output = T>,
+    {
+        let n = self.objective.len();
+        assert!((1..=3).contains(&n), "feasible_region_vertices supports 1 to 3 variables, got {}", n);
+
+        assert!(!self.is_shifted(), "feasible_region_vertices does not support shifted variables");
+        let mut split = self.clone();
+        split.split_ranges();
+        let mut planes: Vec<(Vec<T>, T)> = split
+            .constraints
+            .iter()
+            .map(|c| (c.coefficients(), c.rhs))
+            .collect();
+        let sign = |j: usize| self.variable_signs.get(j).copied().unwrap_or_default();
+        for j in (0..n).filter(|&j| sign(j) != Sign::Free) {
+            let mut unit = vec![T::zero(); n];
+            unit[j] = T::one();
+            planes.push((unit, T::zero()));
+        }
+
+        let mut vertices: Vec<Vec<T>> = Vec::new();
+        let mut subset: Vec<usize> = (0..n).collect();
+        if planes.len() < n {
+            return vertices;
+        }
+        loop {
+            let data = subset.iter().flat_map(|&i| planes[i].0.iter().copied()).collect();
+            let a = Matrix { rows: n, cols: n, data };
+            let b: Vec<T> = subset.iter().map(|&i| planes[i].1).collect();
+            if let Some(x) = a.solve(&b) {
+                let feasible = x.iter().enumerate().all(|(j, &v)| match sign(j) {
+                    Sign::NonNegative => v >= T::zero(),
+                    Sign::NonPositive => v <= T::zero(),
+                    Sign::Free => true,
+                }) && self.constraints.iter().all(|c| c.is_satisfied_by(&x));
+                if feasible && !vertices.contains(&x) {
+                    vertices.push(x);
+                }
+            }
+
+            // Advance to the next n-subset in lexicographic order.
+            let Some(k) = (0..n).rev().find(|&k| subset[k] < planes.len() - n + k) else {
+                break;
+            };
+            subset[k] += 1;
+            for i in k + 1..n {
+                subset[i] = subset[i - 1] + 1;
+            }
+        }
+        vertices
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    let (mut x, mut y) = (a.abs(), b.abs());
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    x
+}
+
+fn checked_lcm(a: i64, b: i64) -> Option<i64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+impl Problem<Rational64> {
+    /// Divides the objective by the gcd of its numerators and returns that
+    /// factor; multiply the reported optimum by it to recover the original
+    /// value. The optimal point is unchanged. Returns 1 for an all-zero objective.
+    pub fn reduce_objective_gcd(&mut self) -> Rational64 {
+        let g = self.objective.iter().fold(0i64, |g, v| gcd(g, *v.numer()));
+        if g <= 1 {
+            return Rational64::one();
+        }
+        let factor = Rational64::from_integer(g);
+        for v in &mut self.objective {
+            *v /= factor;
+        }
+        factor
+    }
+
+    /// Divides each constraint row and its RHS by the gcd of their numerators.
+    /// The factor is positive, so relations and the feasible region are unchanged.
+    pub fn gcd_reduce_constraints(&mut self) {
+        for c in &mut self.constraints {
+            let g = c
+                .sparse_entries()
+                .iter()
+                .map(|(_, v)| v)
+                .chain(std::iter::once(&c.rhs))
+                .chain(&c.range)
+                .fold(0i64, |g, v| gcd(g, *v.numer()));
+            if g > 1 {
+                let factor = Rational64::from_integer(g);
+                c.map_stored(|v| *v /= factor);
+                c.rhs /= factor;
+                if let Some(r) = &mut c.range {
+                    *r /= factor;
+                }
+            }
+        }
+    }
+
+    /// A Big-M penalty large enough that penalising artificial variables by it
+    /// cannot change the optimum of a feasible problem.
+    ///
+    /// With each row scaled by the lcm `l_i` of its denominators, every basis
+    /// matrix of `[A | I | artificials]` is integral, so by Cramer's rule and
+    /// Hadamard's inequality any dual value satisfies
+    /// `|y_i| <= l_i * sum|c_j| * prod_k r_k`, where `r_k` is the scaled row's
+    /// absolute sum plus 2 (slack and artificial). One more than that bound
+    /// exceeds the optimal duals, which is what makes the penalty exact.
+    ///
+    /// The product grows exponentially with the number of rows, so this
+    /// returns `None` once it no longer fits in a `Rational64`.
+    pub fn big_m_value(&self) -> Option<Rational64> {
+        let one = Rational64::one();
+        let two = Rational64::from_integer(2);
+        let c_norm = self
+            .objective
+            .iter()
+            .try_fold(Rational64::zero(), |acc, c| acc.checked_add(&c.abs()))?;
+
+        let mut split = self.clone();
+        split.split_ranges();
+        let mut max_scale = one;
+        let mut hadamard = one;
+        for constraint in &split.constraints {
+            let entries = constraint.sparse_entries();
+            let scale = entries
+                .iter()
+                .try_fold(1i64, |l, (_, v)| checked_lcm(l, *v.denom()))?;
+            let scale = Rational64::from_integer(scale);
+            let row_norm = entries
+                .iter()
+                .map(|(_, v)| v)
+                .try_fold(Rational64::zero(), |acc, v| acc.checked_add(&v.checked_mul(&scale)?.abs()))?;
+            hadamard = hadamard.checked_mul(&row_norm.checked_add(&two)?)?;
+            if scale > max_scale {
+                max_scale = scale;
+            }
+        }
+        max_scale.checked_mul(&c_norm)?.checked_mul(&hadamard)?.checked_add(&one)
+    }
+}
+
+impl<T> Problem<T>
+where
+    T: Clone + Zero + PartialOrd + Neg<Output = T>,
+{
+    /// Inverse of `into_standard_form`; see `StandardForm::into_problem`.
+    pub fn from_standard_form(sf: StandardForm<T>) -> Self {
+        sf.into_problem()
+    }
+}
+
+impl<T> Problem<T>
+where
+    T: Copy + Default + PartialOrd + Neg<Output = T> + Hash,
+{
+    /// Hash of the problem up to constraint order and the sign of each row's
+    /// RHS (rows are normalised first), for memoising repeated solves. Uses
+    /// std's `DefaultHasher`, so values are only comparable within one build.
+    pub fn canonical_hash(&self) -> u64
+    where
+        T: Zero,
+    {
+        let mut rows: Vec<u64> = self
+            .constraints
+            .iter()
+            .map(|c| {
+                let c = c.clone().normalise();
+                let mut h = DefaultHasher::new();
+                (c.coefficients(), &c.relation, &c.rhs, &c.range).hash(&mut h);
+                h.finish()
+            })
+            .collect();
+        rows.sort_unstable();
+
+        // Missing signs and offsets mean non-negative and zero, so pad both
+        // to full length before hashing.
+        let n = self.objective.len();
+        let mut signs = self.variable_signs.clone();
+        signs.resize(n, Sign::NonNegative);
+        let mut offsets = self.variable_offsets.clone();
+        offsets.resize(n, T::default());
+
+        let mut h = DefaultHasher::new();
+        self.goal.hash(&mut h);
+        self.objective_sense_normalized().0.hash(&mut h);
+        self.lexicographic_objectives.hash(&mut h);
+        signs.hash(&mut h);
+        offsets.hash(&mut h);
+        rows.hash(&mut h);
+        h.finish()
+    }
+}
+
+impl<T> Problem<T>
+where
+    T: Clone + Add<Output = T> + Neg<Output = T>,
+{
+    /// Returns the LP dual. Each variable gives one dual row, which is an
+    /// equality for a free variable and flipped for a non-positive one.
+    /// Shifted variables (see `shift_variable`) are not supported.
+    ///
+    /// Rows are first brought to the canonical direction (`<=` for Max, `>=`
+    /// for Min), and equalities and range rows are split into two opposing
+    /// inequalities, so the dual has one non-negative variable per resulting row:
+    /// `max c'x, Ax <= b`  becomes  `min b'y, A'y >= c`, and vice versa.
+    pub fn dual(&self) -> Problem<T>
+    where
+        T: Zero,
+    {
+        assert!(!self.is_shifted(), "The dual does not support shifted variables");
+        let canonical = match self.goal {
+            Goal::Max => Relation::LessEqual,
+            Goal::Min => Relation::GreaterEqual,
+        };
+
+        let mut split = self.clone();
+        split.split_ranges();
+        let mut rows: Vec<(Vec<T>, T)> = Vec::with_capacity(split.constraints.len());
+        for c in &split.constraints {
+            let negated = || (c.coefficients().into_iter().map(|v| -v).collect(), -c.rhs.clone());
+            if c.relation == Relation::Equal {
+                rows.push((c.coefficients(), c.rhs.clone()));
+                rows.push(negated());
+            } else if c.relation == canonical {
+                rows.push((c.coefficients(), c.rhs.clone()));
+            } else {
+                rows.push(negated());
+            }
+        }
+
+        let (dual_goal, dual_relation) = match self.goal {
+            Goal::Max => (Goal::Min, Relation::GreaterEqual),
+            Goal::Min => (Goal::Max, Relation::LessEqual),
+        };
+        let mut dual = Problem::new(rows.iter().map(|(_, b)| b.clone()).collect(), dual_goal);
+        for (j, c_j) in self.objective.iter().enumerate() {
+            let column = rows.iter().map(|(a, _)| a[j].clone()).collect();
+            let relation = match self.variable_signs.get(j).copied().unwrap_or_default() {
+                Sign::NonNegative => dual_relation.clone(),
+                Sign::NonPositive if dual_relation == Relation::LessEqual => Relation::GreaterEqual,
+                Sign::NonPositive => Relation::LessEqual,
+                Sign::Free => Relation::Equal,
+            };
+            dual.add_constraint(column, relation, c_j.clone());
+        }
+        dual
+    }
+}
+
+impl<T> Problem<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    /// Objective rewritten as a minimisation, and whether it had to be
+    /// negated (true for `Max`). Map values back with `Goal::from_minimized`.
+    pub fn objective_sense_normalized(&self) -> (Vec<T>, bool) {
+        let negated = self.goal == Goal::Max;
+        let coeffs = self
+            .objective
+            .iter()
+            .map(|v| if negated { -v.clone() } else { v.clone() })
+            .collect();
+        (coeffs, negated)
     }
 }
 
@@ -69,10 +856,17 @@ where
         self.clone().into_tableau_form()
     }
 
-    pub fn into_standard_form(self) -> StandardForm<T> {
+    pub fn into_standard_form(mut self) -> StandardForm<T> {
+        assert!(
+            self.variable_signs.iter().all(|&s| s == Sign::NonNegative),
+            "Standard form requires non-negative variables; solve the Problem directly"
+        );
+        assert!(!self.is_shifted(), "Standard form does not support shifted variables; solve the Problem directly");
         let one = T::one();
         let zero = T::zero();
 
+        self.split_ranges();
+        let (objective, _) = self.objective_sense_normalized();
         let surplus_slack = self.constraints.iter().filter(|c| c.relation != Relation::Equal).count();
         let total_cols = self.objective.len() + surplus_slack;
 
@@ -84,12 +878,12 @@ where
         for constraint in self.constraints {
             let normalised = constraint.normalise();
             let mut row_data: Vec<T> = Vec::with_capacity(total_cols);
-            row_data.extend(normalised.coefficients);
-            row_data.resize(total_cols, zero.clone());
+            row_data.extend(normalised.coefficients());
+            row_data.resize(total_cols, zero);
 
             match normalised.relation {
                 Relation::LessEqual => {
-                    row_data[slack_index] = one.clone();
+                    row_data[slack_index] = one;
                     slack_indices.push(slack_index);
                 },
                 Relation::GreaterEqual => {
@@ -107,15 +901,8 @@ where
             a_matrix.push_row(&row_data);
         }
 
-        let mut c_vec = vec![zero.clone(); total_cols];
-
-        for (i, val) in self.objective.into_iter().enumerate() {
-            c_vec[i] = if self.goal == Goal::Max {
-                -val
-            } else {
-                val
-            };
-        }
+        let mut c_vec = vec![zero; total_cols];
+        c_vec[..objective.len()].copy_from_slice(&objective);
 
         StandardForm {
             a: a_matrix,
@@ -126,23 +913,41 @@ where
         }
     }
 
-    pub fn into_tableau_form(self) -> Tableau<T> {
+    fn substitute_signs(&self, coeffs: Vec<T>) -> Vec<T> {
+        substitute_signs(&self.variable_signs, coeffs)
+    }
+
+    pub fn into_tableau_form(mut self) -> Tableau<T> {
         let one = T::one();
         let zero = T::zero();
 
+        if self.variable_signs.len() != self.objective.len() {
+            self.variable_signs.resize(self.objective.len(), Sign::NonNegative);
+        }
+        let (objective, _) = self.objective_sense_normalized();
+        let constant = objective
+            .iter()
+            .zip(&self.variable_offsets)
+            .fold(zero, |acc, (&c, &o)| acc + c * o);
+        let objective = self.substitute_signs(objective);
         let m = self.constraints.len();
-        let n = self.objective.len();
+        let n = objective.len();
         let total_cols = n + m + 1;
 
         let mut data = Matrix::with_capacity(m + 1, total_cols);
         let mut basis = Vec::with_capacity(m);
         let nonbasis: Vec<usize> = (0..n).collect();
+        let mut upper_bounds = vec![None; n + m];
+        let mut complemented = vec![false; n + m];
 
-        for (i, constraint) in self.constraints.into_iter().enumerate() {
-            let normalised = constraint.normalise();
+        let constraints = std::mem::take(&mut self.constraints);
+        for (i, constraint) in constraints.into_iter().enumerate() {
+            // Sparse rows are expanded here, as the tableau is dense.
+            let coefficients = Storage::Dense(self.substitute_signs(constraint.coefficients()));
+            let mut normalised = Constraint { coefficients, ..constraint }.normalise();
             let mut row_data = Vec::with_capacity(total_cols);
 
-            row_data.extend(normalised.coefficients);
+            row_data.extend(normalised.coefficients());
 
             let mut slack_part = vec![zero; m];
             match normalised.relation {
@@ -150,6 +955,16 @@ where
                 Relation::GreaterEqual => { slack_part[i] = -one; },
                 Relation::Equal => {},
             }
+            if let Some(range) = normalised.range.filter(|_| normalised.relation != Relation::Equal) {
+                upper_bounds[n + i] = Some(range);
+                // A slack starting above its bound is stored as its complement
+                // `range - s`, leaving the row for Phase I.
+                if normalised.relation == Relation::LessEqual && normalised.rhs > range {
+                    normalised.rhs = normalised.rhs + -range;
+                    slack_part[i] = -one;
+                    complemented[n + i] = true;
+                }
+            }
             row_data.extend(slack_part);
             row_data.push(normalised.rhs);
 
@@ -158,13 +973,22 @@ where
         }
 
         let mut z_row_data = Vec::with_capacity(total_cols);
-        for val in self.objective {
-            z_row_data.push(if self.goal == Goal::Max { -val } else { val });
-        }
+        z_row_data.extend(objective);
         z_row_data.extend(vec![zero; m]);
-        z_row_data.push(zero);
+        z_row_data.push(-constant);
         data.push_row(&z_row_data);
 
-        Tableau { data, n, m, basis, nonbasis }
+        Tableau {
+            data,
+            n,
+            m,
+            basis,
+            nonbasis,
+            var_signs: self.variable_signs,
+            var_offsets: self.variable_offsets,
+            upper_bounds,
+            complemented,
+            pivot_log: Vec::new(),
+        }
     }
 }
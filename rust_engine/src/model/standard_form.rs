@@ -1,10 +1,13 @@
-use crate::linalg::Matrix;
+use crate::linalg::{Matrix, SparseMatrix};
 use crate::model::tableau_form::Tableau;
+use crate::model::{Problem, Relation};
 use super::Goal;
 use num_traits::Zero;
+use std::ops::Neg;
 
 /// Standard form LP: A, b, c, goal, and slack column indices.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StandardForm<T> {
     pub a: Matrix<T>,
     pub b: Vec<T>,
@@ -28,6 +31,15 @@ impl<T> StandardForm<T> {
         }
     }
 
+    /// Builds standard form from a sparse constraint matrix. The tableau is
+    /// dense, so `a` is expanded here.
+    pub fn from_sparse(a: &SparseMatrix<T>, b: Vec<T>, c: Vec<T>, goal: Goal, slack_indices: Vec<usize>) -> Self
+    where
+        T: Clone + Default + Zero,
+    {
+        Self::new(a.to_dense(), b, c, goal, slack_indices)
+    }
+
     /// Number of original (non-slack) variables.
     pub fn n_vars(&self) -> usize {
         self.a.cols - self.slack_indices.len()
@@ -76,3 +88,29 @@ where
         Tableau::new(data, n, m)
     }
 }
+
+impl<T> StandardForm<T>
+where
+    T: Clone + Zero + PartialOrd + Neg<Output = T>,
+{
+    /// Rebuilds a `Problem` over the non-slack columns. Each row's relation is
+    /// read back from the sign of its slack entry (none means `=`), and the
+    /// objective is mapped back from the minimised form to `goal`'s sense.
+    pub fn into_problem(self) -> Problem<T> {
+        let originals: Vec<usize> = (0..self.a.cols).filter(|c| !self.slack_indices.contains(c)).collect();
+        let objective = originals.iter().map(|&c| self.goal.from_minimized(self.c[c].clone())).collect();
+        let mut problem = Problem::new(objective, self.goal);
+
+        for (r, rhs) in self.b.into_iter().enumerate() {
+            let coefficients = originals.iter().map(|&c| self.a[(r, c)].clone()).collect();
+            let slack = self.slack_indices.iter().map(|&c| &self.a[(r, c)]).find(|v| !v.is_zero());
+            let relation = match slack {
+                Some(v) if *v > T::zero() => Relation::LessEqual,
+                Some(_) => Relation::GreaterEqual,
+                None => Relation::Equal,
+            };
+            problem.add_constraint(coefficients, relation, rhs);
+        }
+        problem
+    }
+}
@@ -1,5 +1,6 @@
 use crate::linalg::{Matrix, Row, RowMut};
-use num_traits::Zero;
+use crate::model::Sign;
+use num_traits::{One, Zero};
 use std::ops::{Index, IndexMut};
 
 /// Unified simplex tableau stored as a single (m+1) x (n+m+1) matrix:
@@ -15,6 +16,7 @@ use std::ops::{Index, IndexMut};
 ///        └────────────┴────────────┴──────────┘
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tableau<T> {
     pub data: Matrix<T>,
     /// Number of structural (decision) variables.
@@ -23,6 +25,21 @@ pub struct Tableau<T> {
     pub m: usize,
     pub basis: Vec<usize>,
     pub nonbasis: Vec<usize>,
+    /// Sign restriction of each original variable when built from a `Problem`
+    /// (free variables own an extra column after the originals); empty means
+    /// every structural column is a non-negative original variable.
+    pub var_signs: Vec<Sign>,
+    /// Offsets added back to the original variables by `current_vertex`
+    /// (see `Problem::shift_variable`); empty when nothing is shifted.
+    pub var_offsets: Vec<T>,
+    /// Upper bound of each variable column, `None` (or missing) when it has
+    /// none. Only the slack of a range constraint is bounded (`0 <= s <= range`).
+    pub upper_bounds: Vec<Option<T>>,
+    /// Columns currently holding the complement `u - x` of their bounded
+    /// variable rather than `x` itself (see `flip_bound`); missing means false.
+    pub complemented: Vec<bool>,
+    /// `(row, col)` of every pivot applied to this tableau, in order.
+    pub(crate) pivot_log: Vec<(usize, usize)>,
 }
 
 impl<T> Tableau<T>
@@ -37,7 +54,18 @@ where
         let basis: Vec<usize> = (n..n + m).collect();
         let nonbasis: Vec<usize> = (0..n).collect();
 
-        Self { data, n, m, basis, nonbasis }
+        Self {
+            data,
+            n,
+            m,
+            basis,
+            nonbasis,
+            var_signs: Vec::new(),
+            var_offsets: Vec::new(),
+            upper_bounds: Vec::new(),
+            complemented: Vec::new(),
+            pivot_log: Vec::new(),
+        }
     }
 
     /// Assembles a tableau from separate coefficient matrix, slack matrix, RHS,
@@ -78,6 +106,109 @@ where
 
         Self::new(data, n, m)
     }
+
+    /// Tableau over only the given constraint rows (in the order given), keeping
+    /// the structural columns, those rows' slack columns, the RHS and the z-row.
+    /// Slack columns of dropped rows are discarded, so each kept row's basic
+    /// variable must be structural or one of the kept slacks.
+    pub fn clone_subtableau(&self, rows: &[usize]) -> Tableau<T> {
+        let (n, k) = (self.n, rows.len());
+        for (p, &r) in rows.iter().enumerate() {
+            assert!(r < self.m, "Row {} out of range for {} constraint rows", r, self.m);
+            assert!(!rows[..p].contains(&r), "Row {} selected twice", r);
+        }
+        let cols: Vec<usize> = (0..n)
+            .chain(rows.iter().map(|&r| n + r))
+            .chain(std::iter::once(self.rhs_col()))
+            .collect();
+
+        let mut data = Matrix::with_capacity(k + 1, n + k + 1);
+        for &r in rows.iter().chain(std::iter::once(&self.m)) {
+            let row_data: Vec<T> = cols.iter().map(|&c| self.data[(r, c)].clone()).collect();
+            data.push_row(&row_data);
+        }
+
+        let basis: Vec<usize> = rows
+            .iter()
+            .map(|&r| match self.basis[r] {
+                var if var < n => var,
+                var => n + rows
+                    .iter()
+                    .position(|&kept| n + kept == var)
+                    .unwrap_or_else(|| panic!("Row {} is basic in the slack of a dropped row", r)),
+            })
+            .collect();
+
+        let mut sub = Self::new(data, n, k);
+        sub.var_signs = self.var_signs.clone();
+        sub.var_offsets = self.var_offsets.clone();
+        if !self.upper_bounds.is_empty() {
+            sub.upper_bounds = cols[..n + k].iter().map(|&c| self.upper_bound(c)).collect();
+            sub.complemented = cols[..n + k].iter().map(|&c| self.is_complemented(c)).collect();
+        }
+        sub.nonbasis = (0..n + k).filter(|j| !basis.contains(j)).collect();
+        sub.basis = basis;
+        sub
+    }
+}
+
+impl<T> Tableau<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Cells that differ from `other` as `(row, col, self_value, other_value)`,
+    /// row-major. Panics if the two tableaux have different shapes.
+    pub fn diff(&self, other: &Tableau<T>) -> Vec<(usize, usize, T, T)> {
+        assert_eq!(
+            (self.data.rows, self.data.cols),
+            (other.data.rows, other.data.cols),
+            "Tableaux must have the same shape"
+        );
+        let mut cells = Vec::new();
+        for r in 0..self.data.rows {
+            for c in 0..self.data.cols {
+                if self.data[(r, c)] != other.data[(r, c)] {
+                    cells.push((r, c, self.data[(r, c)].clone(), other.data[(r, c)].clone()));
+                }
+            }
+        }
+        cells
+    }
+}
+
+impl<T> Tableau<T>
+where
+    T: Clone + Default + Zero + One + PartialEq,
+{
+    /// Like `from_parts`, but starts from the given basis instead of the slack
+    /// columns. Panics unless `basis[i]` is a distinct variable column holding
+    /// the unit vector `e_i` in the constraint rows.
+    pub fn from_matrices_with_basis(
+        coefficients: Matrix<T>,
+        slack: Matrix<T>,
+        rhs: Vec<T>,
+        z_coeffs: Vec<T>,
+        z_slack: Vec<T>,
+        z_rhs: T,
+        basis: Vec<usize>,
+    ) -> Self {
+        let mut tab = Self::from_parts(coefficients, slack, rhs, z_coeffs, z_slack, z_rhs);
+        let (n, m) = (tab.n, tab.m);
+        assert_eq!(basis.len(), m, "Basis must have one variable per constraint row");
+        for (i, &col) in basis.iter().enumerate() {
+            assert!(col < n + m, "Basis column {} out of range", col);
+            assert!(!basis[..i].contains(&col), "Basis column {} appears twice", col);
+            for r in 0..m {
+                let entry = &tab.data[(r, col)];
+                let is_unit = if r == i { entry.is_one() } else { entry.is_zero() };
+                assert!(is_unit, "Basis column {} is not the unit vector for row {}", col, i);
+            }
+        }
+
+        tab.nonbasis = (0..n + m).filter(|j| !basis.contains(j)).collect();
+        tab.basis = basis;
+        tab
+    }
 }
 
 impl<T> Tableau<T> {
@@ -96,12 +227,49 @@ impl<T> Tableau<T> {
         self.n + self.m
     }
 
+    /// Number of structural (decision) variable columns.
+    pub fn num_structural_vars(&self) -> usize {
+        self.n
+    }
+
+    /// Number of slack/surplus columns, one per constraint row.
+    pub fn num_slack_vars(&self) -> usize {
+        self.m
+    }
+
+    /// `(row, col)` of each pivot performed on this tableau, oldest first.
+    /// Replaying them on the starting tableau reproduces the current one
+    /// unless a bounded column was flipped (see `flip_bound`); Phase I pivots
+    /// on the auxiliary tableau are not included.
+    pub fn pivot_history(&self) -> &[(usize, usize)] {
+        &self.pivot_log
+    }
+
     pub fn rhs_col(&self) -> usize {
         self.n + self.m
     }
+
+    /// Ordered column headers: `x{j}` for the first `n_vars` columns, `s{k}`
+    /// for the remaining variable columns, then `RHS`.
+    pub fn column_labels(&self, n_vars: usize) -> Vec<String> {
+        (0..self.num_vars())
+            .map(|j| if j < n_vars { format!("x{}", j) } else { format!("s{}", j - n_vars) })
+            .chain(std::iter::once("RHS".to_string()))
+            .collect()
+    }
 }
 
 impl<T: Clone> Tableau<T> {
+    /// Upper bound of variable column `col`, if it has one.
+    pub fn upper_bound(&self, col: usize) -> Option<T> {
+        self.upper_bounds.get(col).cloned().flatten()
+    }
+
+    /// Whether column `col` holds the complement of its variable.
+    pub fn is_complemented(&self, col: usize) -> bool {
+        self.complemented.get(col).copied().unwrap_or(false)
+    }
+
     /// RHS value for constraint row i.
     pub fn rhs(&self, i: usize) -> T {
         self.data[(i, self.rhs_col())].clone()
@@ -138,6 +306,11 @@ impl<T: Clone> Tableau<T> {
     pub fn z_row_mut(&mut self) -> RowMut<'_, T> {
         self.data.row_mut(self.m)
     }
+
+    /// Full (rows+1) x cols matrix, z-row last, for handing to other numeric code.
+    pub fn to_dense_matrix(&self) -> Matrix<T> {
+        self.data.clone()
+    }
 }
 
 impl<T: Copy> Tableau<T> {
@@ -171,6 +344,13 @@ impl<T: Copy> Tableau<T> {
         let m = self.m;
         (0..self.num_vars()).map(|j| self.data[(m, j)]).collect()
     }
+
+    /// Reduced cost of variable column `col` (structural or slack), read from
+    /// the z-row in min-convention: negative means entering improves the objective.
+    pub fn reduced_cost(&self, col: usize) -> T {
+        assert!(col < self.num_vars(), "Column index out of bounds");
+        self.data[(self.m, col)]
+    }
 }
 
 impl<T> Tableau<T>
@@ -182,6 +362,13 @@ where
         let c = self.rhs_col();
         (0..self.m).any(|i| self.data[(i, c)] < T::zero())
     }
+
+    /// True when some basic variable is zero, i.e. the current BFS is a
+    /// degenerate vertex where pivots may not move.
+    pub fn is_degenerate(&self) -> bool {
+        let c = self.rhs_col();
+        (0..self.m).any(|i| self.data[(i, c)].is_zero())
+    }
 }
 
 impl<T> Index<(usize, usize)> for Tableau<T> {
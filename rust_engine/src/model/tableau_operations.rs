@@ -1,18 +1,42 @@
-use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div};
-use crate::model::Tableau;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, Neg};
+use crate::linalg::{reciprocal, Matrix, Row};
+use crate::model::{Sign, Tableau};
 use num_traits::{One, Zero};
 
-/// Pivot selection outcome: Optimal, Unbounded, or Pivot(row, col).
+/// Pivot selection outcome: Optimal, Unbounded, Pivot(row, col), or
+/// BoundFlip(col) when the entering column reaches its own upper bound before
+/// any basic variable blocks it (it stays nonbasic; see `Tableau::flip_bound`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PivotResult {
     Optimal,
     Unbounded,
     Pivot(usize, usize),
+    BoundFlip(usize),
+}
+
+/// Which column wins when several share the most negative reduced cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    #[default]
+    Lowest,
+    Highest,
+}
+
+/// Entering-column rule for `SimplexSolver`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotRule {
+    /// Most negative reduced cost.
+    #[default]
+    Dantzig,
+    /// First negative reduced cost; never cycles.
+    Bland,
+    /// Most negative reduced cost per unit length of the tableau column.
+    SteepestEdge,
 }
 
 impl<T> Tableau<T>
 where
-    T: Zero + PartialOrd + Clone + Copy + Div<Output = T>,
+    T: Zero + PartialOrd + Clone + Copy + Sub<Output = T> + Div<Output = T>,
 {
     /// Z-row entries (column index, value) for variable columns only (excludes RHS).
     fn z_row_entries(&self) -> impl Iterator<Item = (usize, T)> + '_ {
@@ -21,11 +45,21 @@ where
     }
 
     /// Pivot column by Dantzig rule (most negative reduced cost).
+    /// Ties go to the lowest column index.
     pub fn find_pivot_col_most_negative(&self) -> Option<usize> {
+        self.find_pivot_col_most_negative_with(TieBreak::Lowest)
+    }
+
+    /// Dantzig rule with an explicit tie-break between equally negative columns.
+    pub fn find_pivot_col_most_negative_with(&self, tie_break: TieBreak) -> Option<usize> {
         let mut best_col = None;
         let mut min_val = T::zero();
         for (j, val) in self.z_row_entries() {
-            if val < min_val {
+            let better = match tie_break {
+                TieBreak::Lowest => val < min_val,
+                TieBreak::Highest => val < min_val || (best_col.is_some() && val == min_val),
+            };
+            if better {
                 min_val = val;
                 best_col = Some(j);
             }
@@ -40,21 +74,111 @@ where
             .map(|(j, _)| j)
     }
 
+    /// Approximate steepest-edge rule: among negative reduced costs `d_j`,
+    /// the column maximising `d_j^2 / (1 + |a_j|^2)` over the current tableau
+    /// column `a_j` (squared so the comparison stays exact). Ties go to the
+    /// lowest column index.
+    pub fn find_pivot_col_steepest_edge(&self) -> Option<usize>
+    where
+        T: One + Add<Output = T> + Mul<Output = T>,
+    {
+        let mut best: Option<(usize, T)> = None;
+        for (j, val) in self.z_row_entries() {
+            if val < T::zero() {
+                let norm_sq = (0..self.m).fold(T::one(), |acc, i| acc + self.data[(i, j)] * self.data[(i, j)]);
+                let score = val * val / norm_sq;
+                if best.is_none_or(|(_, s)| score > s) {
+                    best = Some((j, score));
+                }
+            }
+        }
+        best.map(|(j, _)| j)
+    }
+
+    /// Partial pricing: the most negative reduced cost within the first
+    /// `chunk`-wide window of columns, starting at `start` and wrapping, that
+    /// holds any negative entry. Falls back to later windows until every
+    /// column has been priced, so `None` still means optimal.
+    pub fn find_pivot_col_partial(&self, start: usize, chunk: usize) -> Option<usize> {
+        assert!(chunk > 0, "Partial pricing chunk must be positive");
+        let n = self.num_vars();
+        let mut best: Option<(usize, T)> = None;
+        for k in 0..n {
+            let j = (start + k) % n;
+            let val = self.data[(self.m, j)];
+            if val < T::zero() && best.is_none_or(|(_, b)| val < b) {
+                best = Some((j, val));
+            }
+            if best.is_some() && (k + 1) % chunk == 0 {
+                break;
+            }
+        }
+        best.map(|(j, _)| j)
+    }
+
+    /// Objective improvement from pivoting `col` in: `-d_col * theta` for
+    /// reduced cost `d_col` and ratio-test step `theta`, so positive means
+    /// better in either goal sense (zero for a degenerate pivot). `None` when
+    /// the column does not improve (`d_col >= 0`) or the ray is unbounded.
+    pub fn improvement_of_entering(&self, col: usize) -> Option<T>
+    where
+        T: Mul<Output = T> + Neg<Output = T>,
+    {
+        assert!(col < self.num_vars(), "Column index {} out of range", col);
+        let reduced_cost = self.data[(self.m, col)];
+        if reduced_cost >= T::zero() {
+            return None;
+        }
+        let theta = match self.pivot_or_flip(col, self.ratio_test(col)) {
+            PivotResult::Pivot(row, _) => self.row_ratio(row, col)?,
+            PivotResult::BoundFlip(_) => self.upper_bound(col)?,
+            _ => return None,
+        };
+        Some(-reduced_cost * theta)
+    }
+
+    /// How far entering column `col` can rise before row `i`'s basic
+    /// variable falls to zero, or, for a negative entry, climbs to its upper
+    /// bound. `None` when the row does not block the column.
+    fn row_ratio(&self, i: usize, col: usize) -> Option<T> {
+        let entry = self.data[(i, col)];
+        if entry > T::zero() {
+            return Some(self.rhs(i) / entry);
+        }
+        if entry < T::zero() {
+            let bound = self.upper_bound(self.basis[i])?;
+            return Some((bound - self.rhs(i)) / (T::zero() - entry));
+        }
+        None
+    }
+
+    /// Pivot on the ratio-test `row` for entering `col`, unless `col` reaches
+    /// its own upper bound strictly first, in which case it only flips.
+    pub fn pivot_or_flip(&self, col: usize, row: Option<usize>) -> PivotResult {
+        let bound = self.upper_bound(col);
+        match (row, bound) {
+            (Some(row), Some(u)) if self.row_ratio(row, col).is_some_and(|ratio| u < ratio) => {
+                PivotResult::BoundFlip(col)
+            }
+            (Some(row), _) => PivotResult::Pivot(row, col),
+            (None, Some(_)) => PivotResult::BoundFlip(col),
+            (None, None) => PivotResult::Unbounded,
+        }
+    }
+
     /// Minimum-ratio test: returns leaving row for the given entering column, or None.
-    /// Ties are broken by smallest row index.
+    /// Ties are broken by smallest row index. A bounded basic variable also
+    /// blocks the column when it would climb past its upper bound.
     pub fn ratio_test(&self, col: usize) -> Option<usize> {
         let mut best_row = None;
         let mut min_ratio: Option<T> = None;
-        let rhs_col = self.rhs_col();
 
         for i in 0..self.m {
-            let entry = self.data[(i, col)];
-            if entry > T::zero() {
-                let ratio = self.data[(i, rhs_col)] / entry;
-                if min_ratio.is_none() || ratio < min_ratio.unwrap() {
-                    min_ratio = Some(ratio);
-                    best_row = Some(i);
-                }
+            if let Some(ratio) = self.row_ratio(i, col)
+                && (min_ratio.is_none() || ratio < min_ratio.unwrap())
+            {
+                min_ratio = Some(ratio);
+                best_row = Some(i);
             }
         }
         best_row
@@ -67,20 +191,14 @@ where
         let mut best_row = None;
         let mut min_ratio: Option<T> = None;
         let mut best_basis_var: Option<usize> = None;
-        let rhs_col = self.rhs_col();
 
         for i in 0..self.m {
-            let entry = self.data[(i, col)];
-            if entry > T::zero() {
-                let ratio = self.data[(i, rhs_col)] / entry;
-                let update = if min_ratio.is_none() {
-                    true
-                } else if ratio < min_ratio.unwrap() {
-                    true
-                } else if ratio == min_ratio.unwrap() {
-                    self.basis[i] < best_basis_var.unwrap()
-                } else {
-                    false
+            if let Some(ratio) = self.row_ratio(i, col) {
+                let update = match (min_ratio, best_basis_var) {
+                    (Some(best), Some(best_var)) => {
+                        ratio < best || (ratio == best && self.basis[i] < best_var)
+                    }
+                    _ => true,
                 };
                 if update {
                     min_ratio = Some(ratio);
@@ -92,14 +210,25 @@ where
         best_row
     }
 
-    /// Chooses pivot (Dantzig column, ratio test row); returns Optimal, Unbounded, or Pivot(row, col).
+    /// Chooses pivot (Dantzig column, ratio test row); returns Optimal,
+    /// Unbounded, Pivot(row, col), or BoundFlip(col).
     pub fn find_pivot_indices(&self) -> PivotResult {
-        match self.find_pivot_col_most_negative() {
+        self.find_pivot_indices_with(TieBreak::Lowest)
+    }
+
+    /// Same as find_pivot_indices with an explicit entering-column tie-break.
+    pub fn find_pivot_indices_with(&self, tie_break: TieBreak) -> PivotResult {
+        match self.find_pivot_col_most_negative_with(tie_break) {
+            None => PivotResult::Optimal,
+            Some(col) => self.pivot_or_flip(col, self.ratio_test(col)),
+        }
+    }
+
+    /// Partial-pricing column (see `find_pivot_col_partial`) with the usual ratio test.
+    pub fn find_pivot_indices_partial(&self, start: usize, chunk: usize) -> PivotResult {
+        match self.find_pivot_col_partial(start, chunk) {
             None => PivotResult::Optimal,
-            Some(col) => match self.ratio_test(col) {
-                Some(row) => PivotResult::Pivot(row, col),
-                None => PivotResult::Unbounded,
-            },
+            Some(col) => self.pivot_or_flip(col, self.ratio_test(col)),
         }
     }
 
@@ -120,10 +249,18 @@ where
     pub fn find_pivot_indices_cycling_prone(&self) -> PivotResult {
         match self.find_pivot_col_largest_index() {
             None => PivotResult::Optimal,
-            Some(col) => match self.ratio_test_smallest_basis(col) {
-                Some(row) => PivotResult::Pivot(row, col),
-                None => PivotResult::Unbounded,
-            },
+            Some(col) => self.pivot_or_flip(col, self.ratio_test_smallest_basis(col)),
+        }
+    }
+
+    /// Same as find_pivot_indices but enters by the steepest-edge rule.
+    pub fn find_pivot_indices_steepest_edge(&self) -> PivotResult
+    where
+        T: One + Add<Output = T> + Mul<Output = T>,
+    {
+        match self.find_pivot_col_steepest_edge() {
+            None => PivotResult::Optimal,
+            Some(col) => self.pivot_or_flip(col, self.ratio_test(col)),
         }
     }
 
@@ -131,26 +268,56 @@ where
     pub fn find_pivot_indices_bland(&self) -> PivotResult {
         match self.find_pivot_col_bland() {
             None => PivotResult::Optimal,
-            Some(col) => match self.ratio_test(col) {
-                Some(row) => PivotResult::Pivot(row, col),
-                None => PivotResult::Unbounded,
-            },
+            Some(col) => self.pivot_or_flip(col, self.ratio_test(col)),
         }
     }
 
     /// Current BFS as a vector of length n_vars (non-basic vars = 0, basic = RHS).
+    /// Entries for sign-restricted original variables (see `var_signs`) are
+    /// mapped back from their substituted columns, then shifted by `var_offsets`.
     pub fn current_vertex(&self, n_vars: usize) -> Vec<T>
+    where
+        T: Zero + Clone + Sub<Output = T>,
+    {
+        let mut values = self.basic_variable_values();
+        values.resize(n_vars.max(values.len()), T::zero());
+
+        let mut vertex = self.undo_sign_substitution(&values, n_vars);
+        for (v, off) in vertex.iter_mut().zip(&self.var_offsets) {
+            *v = *v + *off;
+        }
+        vertex
+    }
+
+    /// Value of every variable column (structural then slack), length `n+m`:
+    /// basic variables take their row's RHS, nonbasic ones are zero, and a
+    /// complemented column reports its variable `u - x` rather than `x`.
+    pub fn basic_variable_values(&self) -> Vec<T>
     where
         T: Zero + Clone,
     {
-        let mut vertex = vec![T::zero(); n_vars];
+        let mut values = vec![T::zero(); self.num_vars()];
         let rhs_col = self.rhs_col();
         for (row, &var_idx) in self.basis.iter().enumerate() {
-            if var_idx < n_vars {
-                vertex[var_idx] = self.data[(row, rhs_col)].clone();
+            values[var_idx] = self.data[(row, rhs_col)];
+        }
+        for (j, value) in values.iter_mut().enumerate() {
+            if self.is_complemented(j)
+                && let Some(u) = self.upper_bound(j)
+            {
+                *value = u - *value;
             }
         }
-        vertex
+        values
+    }
+
+    /// Slack (or surplus) of each constraint row at the current vertex, as in
+    /// `basic_variable_values`.
+    pub fn slack_values(&self) -> Vec<T>
+    where
+        T: Zero + Clone,
+    {
+        self.basic_variable_values().split_off(self.n)
     }
 
     /// Returns true when no reduced cost is negative.
@@ -184,6 +351,118 @@ where
     }
 }
 
+impl<T> Tableau<T>
+where
+    T: Zero + Copy + Sub<Output = T>,
+{
+    /// First `n_vars` entries of `values` (indexed by tableau column), with
+    /// non-positive and free original variables recombined from their
+    /// substituted columns.
+    fn undo_sign_substitution(&self, values: &[T], n_vars: usize) -> Vec<T> {
+        let mut out = values[..n_vars].to_vec();
+        let mut negative_part = self.var_signs.len();
+        for (j, sign) in self.var_signs.iter().enumerate() {
+            match sign {
+                Sign::NonNegative => {}
+                Sign::NonPositive if j < n_vars => out[j] = T::zero() - values[j],
+                Sign::NonPositive => {}
+                Sign::Free => {
+                    if j < n_vars {
+                        out[j] = values[j] - values[negative_part];
+                    }
+                    negative_part += 1;
+                }
+            }
+        }
+        out
+    }
+
+    /// Z-row entry of each original variable: the positive-part column of a
+    /// free variable, negated for a non-positive one.
+    pub fn original_reduced_costs(&self) -> Vec<T> {
+        let n_vars = if self.var_signs.is_empty() { self.n } else { self.var_signs.len() };
+        (0..n_vars)
+            .map(|j| match self.var_signs.get(j) {
+                Some(Sign::NonPositive) => T::zero() - self.data[(self.m, j)],
+                _ => self.data[(self.m, j)],
+            })
+            .collect()
+    }
+}
+
+impl<T> Tableau<T>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    /// Rows whose basic column is not the unit column for that row.
+    /// `into_tableau_form` starts from the slack basis, which is only a genuine
+    /// BFS for `<=` rows; every `>=` and `=` row shows up here, whatever its RHS.
+    pub fn infeasible_basis_rows(&self) -> Vec<usize> {
+        (0..self.m)
+            .filter(|&i| !self.is_unit_column(self.basis[i], i, T::one()))
+            .collect()
+    }
+
+    /// True when column `col` holds `unit` in row `row` and zero in every
+    /// other constraint row.
+    fn is_unit_column(&self, col: usize, row: usize, unit: T) -> bool {
+        (0..self.m).all(|k| if k == row { self.data[(k, col)] == unit } else { self.data[(k, col)].is_zero() })
+    }
+}
+
+impl<T> Tableau<T>
+where
+    T: Zero + One + PartialOrd + Copy + Neg<Output = T>,
+{
+    /// Improving ray of an unbounded tableau: the first column with a negative
+    /// reduced cost that nothing blocks (no positive entry, no bound reached
+    /// by it or a basic variable), and the direction the original
+    /// variables move in as it increases (sign substitutions undone, as in
+    /// `current_vertex`). `None` if no column qualifies.
+    pub fn unbounded_ray(&self) -> Option<(usize, Vec<T>)>
+    where
+        T: Sub<Output = T> + Div<Output = T>,
+    {
+        let entering = (0..self.num_vars()).find(|&j| {
+            self.data[(self.m, j)] < T::zero() && self.pivot_or_flip(j, self.ratio_test(j)) == PivotResult::Unbounded
+        })?;
+
+        let mut direction = vec![T::zero(); self.n];
+        if entering < self.n {
+            direction[entering] = T::one();
+        }
+        for (i, &var) in self.basis.iter().enumerate() {
+            if var < self.n {
+                direction[var] = -self.data[(i, entering)];
+            }
+        }
+        let n_vars = if self.var_signs.is_empty() { self.n } else { self.var_signs.len() };
+        Some((entering, self.undo_sign_substitution(&direction, n_vars)))
+    }
+
+    /// Repairs constraint rows with a negative RHS. A row whose basic column
+    /// holds `-1` is multiplied by -1, which keeps that column a unit vector;
+    /// any other offending row is left as is and returned, since it needs a
+    /// phase I (`restore_feasible_basis`) instead.
+    pub fn normalize_rhs_nonneg(&mut self) -> Vec<usize> {
+        let rhs_col = self.rhs_col();
+        let mut needs_phase_one = Vec::new();
+        for i in 0..self.m {
+            if self.data[(i, rhs_col)] >= T::zero() {
+                continue;
+            }
+            if self.data[(i, self.basis[i])] == -T::one() {
+                for j in 0..=rhs_col {
+                    self.data[(i, j)] = -self.data[(i, j)];
+                }
+            } else {
+                needs_phase_one.push(i);
+            }
+        }
+        needs_phase_one
+    }
+}
+
 impl<T> Tableau<T>
 where
     T: Zero
@@ -199,10 +478,49 @@ where
         + SubAssign
         + MulAssign,
 {
+    /// Factor that scales the pivot row so the pivot element becomes one.
+    pub fn pivot_scale(&self, row_idx: usize, col_idx: usize) -> T {
+        reciprocal(self.data[(row_idx, col_idx)])
+    }
+
+    /// Like `pivot`, but returns an error instead of panicking when the
+    /// pivot element is zero.
+    pub fn pivot_exact_check(&mut self, row_idx: usize, col_idx: usize) -> Result<(), String> {
+        if self.data[(row_idx, col_idx)].is_zero() {
+            return Err(format!("cannot pivot on zero element at ({}, {})", row_idx, col_idx));
+        }
+        self.pivot(row_idx, col_idx);
+        Ok(())
+    }
+
+    /// Forces `entering_col` into the basis in place of row `leaving_row`'s
+    /// variable, skipping the ratio test (the result may be infeasible).
+    /// Errors on out-of-range indices or a zero pivot element.
+    pub fn swap_basis(&mut self, leaving_row: usize, entering_col: usize) -> Result<(), String> {
+        if leaving_row >= self.m {
+            return Err(format!("Row {} out of range for {} constraint rows", leaving_row, self.m));
+        }
+        if entering_col >= self.num_vars() {
+            return Err(format!("Column {} out of range for {} variables", entering_col, self.num_vars()));
+        }
+        self.pivot_exact_check(leaving_row, entering_col)
+    }
+
     /// Performs a pivot at (row_idx, col_idx); updates basis and all rows including z-row.
+    /// On a negative pivot element a bounded leaving variable leaves at its
+    /// upper bound, so it is complemented first (see `flip_bound`).
     pub fn pivot(&mut self, row_idx: usize, col_idx: usize) {
-        let pivot_element = self.data[(row_idx, col_idx)];
-        let inv_pivot = T::one() / pivot_element;
+        assert!(
+            !self.data[(row_idx, col_idx)].is_zero(),
+            "cannot pivot on zero element at ({}, {})",
+            row_idx,
+            col_idx
+        );
+        let leaving = self.basis[row_idx];
+        if self.data[(row_idx, col_idx)] < T::zero() && self.upper_bound(leaving).is_some() {
+            self.flip_bound(leaving);
+        }
+        let inv_pivot = self.pivot_scale(row_idx, col_idx);
 
         {
             let mut p_row = self.data.row_mut(row_idx);
@@ -219,5 +537,180 @@ where
         }
 
         self.basis[row_idx] = col_idx;
+        self.pivot_log.push((row_idx, col_idx));
+    }
+
+    /// Moves bounded column `col`'s variable `x` to its other bound by
+    /// substituting `x = u - x'`: every row, z-row included, takes `u` times
+    /// its entry off the RHS and negates the entry. A basic column's row is
+    /// negated as well so the column stays a unit vector.
+    pub fn flip_bound(&mut self, col: usize) {
+        let u = self.upper_bound(col).unwrap_or_else(|| panic!("Column {} has no upper bound", col));
+        let rhs_col = self.rhs_col();
+        for k in 0..=self.m {
+            let entry = self.data[(k, col)];
+            self.data[(k, rhs_col)] -= u * entry;
+            self.data[(k, col)] = T::zero() - entry;
+        }
+        if let Some(row) = self.basis.iter().position(|&b| b == col) {
+            for j in 0..=rhs_col {
+                self.data[(row, j)] = T::zero() - self.data[(row, j)];
+            }
+        }
+        if self.complemented.len() <= col {
+            self.complemented.resize(col + 1, false);
+        }
+        self.complemented[col] = !self.complemented[col];
+    }
+
+    /// Carries out a `Pivot` or `BoundFlip` from one of the pivot rules.
+    /// Returns false, changing nothing, for `Optimal` or `Unbounded`.
+    pub fn apply(&mut self, result: PivotResult) -> bool {
+        match result {
+            PivotResult::Pivot(row, col) => self.pivot(row, col),
+            PivotResult::BoundFlip(col) => self.flip_bound(col),
+            PivotResult::Optimal | PivotResult::Unbounded => return false,
+        }
+        true
+    }
+
+    /// Appends the constraint `coeffs . x + slack_sign * s = rhs` over the
+    /// structural variables, with its own new slack column `s` made basic.
+    /// The row is rewritten in terms of the current basis, so this works on a
+    /// solved tableau (e.g. for cutting planes). A negative resulting RHS means
+    /// the current vertex violates the new row.
+    pub fn append_constraint(&mut self, coeffs: Vec<T>, slack_sign: T, rhs: T)
+    where
+        T: Default,
+    {
+        assert_eq!(coeffs.len(), self.n, "Coefficients length must match number of structural variables");
+        assert!(!slack_sign.is_zero(), "Slack sign must be nonzero");
+
+        // New slack column goes just before the RHS column.
+        self.data.push_column(None);
+        let slack_col = self.rhs_col();
+        self.data.swap_columns(slack_col, slack_col + 1);
+
+        let mut row = Row { data: coeffs };
+        row.data.resize(slack_col, T::zero());
+        row.data.push(T::one());
+        row.data.push(rhs / slack_sign);
+        for v in row.data.iter_mut().take(slack_col) {
+            *v = *v / slack_sign;
+        }
+        for i in 0..self.m {
+            let factor = row.data[self.basis[i]];
+            if !factor.is_zero() {
+                row.sub_assign_scaled(&self.data.row(i), factor);
+            }
+        }
+
+        // New row goes just above the z-row.
+        self.data.append_row_vec(row.data);
+        self.data.swap_rows(self.m, self.m + 1);
+        self.m += 1;
+        self.basis.push(slack_col);
+    }
+
+    /// Phase I: replaces an invalid starting basis with a feasible one by
+    /// minimising the sum of artificial variables on an auxiliary tableau,
+    /// then restores the original objective row for the new basis.
+    /// Returns false if the constraints admit no feasible point.
+    pub fn restore_feasible_basis(&mut self) -> bool
+    where
+        T: Default,
+    {
+        // A degenerate surplus row `-s = 0` is already a valid basis once negated.
+        let rhs_col = self.rhs_col();
+        for i in 0..self.m {
+            if self.rhs(i).is_zero() && self.is_unit_column(self.basis[i], i, T::zero() - T::one()) {
+                for j in 0..=rhs_col {
+                    self.data[(i, j)] = T::zero() - self.data[(i, j)];
+                }
+            }
+        }
+
+        let bad_rows = self.infeasible_basis_rows();
+        if bad_rows.is_empty() {
+            return true;
+        }
+
+        // Auxiliary tableau: every original column (structural + slack) is
+        // treated as structural, and one artificial column is added per row.
+        let width = self.num_vars();
+        let mut data = Matrix::with_capacity(self.m + 1, width + self.m + 1);
+        for i in 0..self.m {
+            let mut row: Vec<T> = (0..width).map(|j| self.data[(i, j)]).collect();
+            row.extend((0..self.m).map(|k| if k == i { T::one() } else { T::zero() }));
+            row.push(self.rhs(i));
+            data.push_row(&row);
+        }
+        let mut z_row = vec![T::zero(); width + self.m + 1];
+        for &i in &bad_rows {
+            for (j, z) in z_row.iter_mut().take(width).enumerate() {
+                *z -= self.data[(i, j)];
+            }
+            z_row[width + self.m] -= self.rhs(i);
+        }
+        data.push_row(&z_row);
+
+        let mut aux = Tableau::new(data, width, self.m);
+        aux.basis = self.basis.clone();
+        aux.upper_bounds = self.upper_bounds.clone();
+        aux.complemented = self.complemented.clone();
+        for &i in &bad_rows {
+            aux.basis[i] = width + i;
+        }
+
+        while aux.apply(aux.find_pivot_indices_bland()) {}
+        if !aux.z_rhs().is_zero() {
+            return false;
+        }
+
+        // Drive any artificial still basic (at zero) out of the basis.
+        for i in 0..self.m {
+            if aux.basis[i] < width {
+                continue;
+            }
+            let entering = (0..width)
+                .find(|&j| !aux.data[(i, j)].is_zero() && !aux.basis.contains(&j));
+            match entering {
+                Some(j) => aux.pivot(i, j),
+                // Redundant row, now `0 = 0`: give it an all-zero column (the
+                // phantom slack of some `=` row) as its own unit column.
+                None => {
+                    let j = (self.n..width)
+                        .find(|&j| !aux.basis.contains(&j) && (0..=self.m).all(|k| aux.data[(k, j)].is_zero()))
+                        .expect("a redundant row implies an unused equality slack column");
+                    aux.data[(i, j)] = T::one();
+                    aux.basis[i] = j;
+                }
+            }
+        }
+
+        // Columns flipped during Phase I flip in the objective row too.
+        let mut cost = self.z_row_vars();
+        let mut cost_rhs = self.z_rhs();
+        for (j, c) in cost.iter_mut().enumerate() {
+            if aux.is_complemented(j) != self.is_complemented(j)
+                && let Some(u) = self.upper_bound(j)
+            {
+                cost_rhs -= u * *c;
+                *c = T::zero() - *c;
+            }
+        }
+        for i in 0..self.m {
+            for j in 0..width {
+                self.data[(i, j)] = aux.data[(i, j)];
+            }
+            self.data[(i, rhs_col)] = aux.rhs(i);
+        }
+        self.basis = aux.basis;
+        self.complemented = aux.complemented;
+
+        let reduced = self.reduced_costs(&cost);
+        let z_rhs = cost_rhs - self.eval_at_basis(&cost);
+        self.set_z_row(&reduced, z_rhs);
+        true
     }
 }
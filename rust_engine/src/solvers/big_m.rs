@@ -0,0 +1,301 @@
+use num_rational::Rational64;
+
+use crate::linalg::Matrix;
+use crate::model::tableau_form::Tableau;
+use crate::model::{Goal, PivotResult, Problem};
+use crate::solvers::{InitSource, Solver, Step, Status};
+use num_traits::{FromPrimitive, Signed, Zero};
+use std::ops::{AddAssign, Div, MulAssign, SubAssign};
+
+/// Scalars for which an exact Big-M penalty can be derived from the problem
+/// data; `None` when the penalty is not representable.
+pub trait BigMPenalty: Sized {
+    fn big_m(problem: &Problem<Self>) -> Option<Self>;
+}
+
+impl BigMPenalty for Rational64 {
+    fn big_m(problem: &Problem<Rational64>) -> Option<Rational64> {
+        problem.big_m_value()
+    }
+}
+
+/// Single-phase Big-M simplex (Bland's rule). Every row whose slack cannot
+/// start basic gets an artificial column costing `M` per unit, with `M` from
+/// `BigMPenalty` (see `Problem::big_m_value`) unless set explicitly; if no
+/// penalty can be derived, `find_initial_bfs` fails. An artificial left
+/// positive at the optimum means the LP is infeasible.
+///
+/// The tableau carries the artificial columns after the slacks, and until
+/// they leave the basis the reported objective includes their penalty.
+pub struct BigMSimplexSolver<T> {
+    tableau: Option<Tableau<T>>,
+    goal: Goal,
+    iteration: usize,
+    n_vars: usize,
+    /// First artificial column; columns from here up to the RHS are artificial.
+    artificial_start: usize,
+    penalty: Option<T>,
+    done: bool,
+    last_step: Option<Step<T>>,
+    prev_primal: Option<Vec<T>>,
+}
+
+impl<T> BigMSimplexSolver<T>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd,
+{
+    pub fn new() -> Self {
+        Self {
+            tableau: None,
+            goal: Goal::Max,
+            iteration: 0,
+            n_vars: 0,
+            artificial_start: 0,
+            penalty: None,
+            done: false,
+            last_step: None,
+            prev_primal: None,
+        }
+    }
+
+    /// Uses `m` as the artificial penalty instead of deriving one at `init()`.
+    pub fn set_penalty(&mut self, m: T) {
+        self.penalty = Some(m);
+    }
+
+    /// True when some artificial column is basic at a positive value.
+    fn artificial_positive(&self) -> bool {
+        let tab = self.tableau.as_ref().unwrap();
+        tab.basis
+            .iter()
+            .enumerate()
+            .any(|(i, &j)| j >= self.artificial_start && tab.rhs(i) > T::zero())
+    }
+
+    /// Whether the artificials can all reach zero, found by minimising their
+    /// sum with Bland's rule on a copy of the tableau. Tells an infeasible LP
+    /// from an unbounded one when the penalised problem is unbounded.
+    fn artificials_removable(&self) -> bool
+    where
+        T: Default,
+    {
+        let mut tab = self.tableau.as_ref().unwrap().clone();
+        let (m, rhs_col) = (tab.m, tab.rhs_col());
+        for j in 0..=rhs_col {
+            let cost = if j >= self.artificial_start && j < rhs_col { T::one() } else { T::zero() };
+            let priced = (0..m)
+                .filter(|&i| tab.basis[i] >= self.artificial_start)
+                .fold(cost, |acc, i| acc - tab.data[(i, j)]);
+            tab.data[(m, j)] = priced;
+        }
+        while tab.apply(tab.find_pivot_indices_bland()) {}
+        !tab.basis
+            .iter()
+            .enumerate()
+            .any(|(i, &j)| j >= self.artificial_start && tab.rhs(i) > T::zero())
+    }
+}
+
+impl<T> Default for BigMSimplexSolver<T>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Appends one artificial column per row of `tab` whose basic column is not a
+/// unit entry, costed at `m` and priced out of the z-row.
+fn with_artificials<T>(tab: Tableau<T>, m: T) -> Tableau<T>
+where
+    T: Zero + Signed + Copy + Default + SubAssign + PartialOrd,
+{
+    let rows: Vec<usize> = (0..tab.m).filter(|&i| !tab.data[(i, tab.basis[i])].is_one()).collect();
+    let width = tab.num_vars();
+    let k = rows.len();
+
+    let mut data = Matrix::with_capacity(tab.m + 1, width + k + 1);
+    for i in 0..tab.m {
+        let mut row: Vec<T> = (0..width).map(|j| tab.data[(i, j)]).collect();
+        row.extend(rows.iter().map(|&r| if r == i { T::one() } else { T::zero() }));
+        row.push(tab.rhs(i));
+        data.push_row(&row);
+    }
+    let mut z_row: Vec<T> = (0..width).map(|j| tab.data[(tab.m, j)]).collect();
+    z_row.extend(vec![m; k]);
+    z_row.push(tab.z_rhs());
+    for &r in &rows {
+        for (j, z) in z_row.iter_mut().enumerate() {
+            *z -= m * data[(r, j)];
+        }
+    }
+    data.push_row(&z_row);
+
+    // The slack block of `Tableau` must be m wide, so the original slacks
+    // count as structural columns and the artificials fill the rest.
+    let mut out = Tableau::new(data, width + k - tab.m, tab.m);
+    out.basis = tab.basis.clone();
+    for (a, &r) in rows.iter().enumerate() {
+        out.basis[r] = width + a;
+    }
+    out.nonbasis = (0..width + k).filter(|j| !out.basis.contains(j)).collect();
+    out.var_signs = tab.var_signs;
+    out.var_offsets = tab.var_offsets;
+    out.upper_bounds = tab.upper_bounds;
+    out.complemented = tab.complemented;
+    out
+}
+
+impl<T> Solver<T> for BigMSimplexSolver<T>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd
+        + Default
+        + BigMPenalty,
+{
+    type Error = String;
+
+    fn init(&mut self, source: InitSource<T>) {
+        self.goal = source.goal();
+        let m = match (&self.penalty, &source) {
+            (Some(m), _) => Some(*m),
+            (None, InitSource::Problem(p)) => T::big_m(p),
+            (None, InitSource::StandardForm(sf)) => T::big_m(&Problem::from_standard_form(sf.clone())),
+        };
+        let (n_vars, tableau) = source.into_tableau_and_n_vars();
+        self.n_vars = n_vars;
+        self.artificial_start = tableau.num_vars();
+        self.tableau = m.map(|m| with_artificials(tableau, m));
+        self.iteration = 0;
+        self.done = false;
+        self.last_step = None;
+        self.prev_primal = None;
+    }
+
+    fn find_initial_bfs(&mut self) -> Result<bool, Self::Error> {
+        let Some(tab) = self.tableau.as_ref() else {
+            return Err("Big-M penalty overflows; set one with set_penalty".to_string());
+        };
+        if tab.has_negative_rhs() {
+            return Err("Infeasible: initial tableau has negative RHS".to_string());
+        }
+        Ok(true)
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn current_step(&self) -> Step<T> {
+        let tab = self.tableau.as_ref().unwrap();
+        Step {
+            iteration: self.iteration,
+            primal: tab.current_vertex(self.n_vars),
+            objective_value: self.goal.from_minimized(-tab.z_rhs()),
+            status: if self.done { Status::Optimal } else { Status::InProgress },
+            is_degenerate: false,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
+            entering_var: None,
+            leaving_var: None,
+            phase: None,
+        }
+    }
+
+    fn step(&mut self) -> Step<T> {
+        let tab = self.tableau.as_mut().unwrap();
+
+        let (status, entering, leaving) = match tab.find_pivot_indices_bland() {
+            PivotResult::Pivot(row, col) => {
+                let leaving_var = tab.basis[row];
+                tab.pivot(row, col);
+                self.iteration += 1;
+                (Status::InProgress, Some(col), Some(leaving_var))
+            }
+            PivotResult::BoundFlip(col) => {
+                tab.flip_bound(col);
+                self.iteration += 1;
+                (Status::InProgress, Some(col), None)
+            }
+            PivotResult::Optimal => {
+                self.done = true;
+                (Status::Optimal, None, None)
+            }
+            PivotResult::Unbounded => {
+                self.done = true;
+                if self.artificial_positive() && !self.artificials_removable() {
+                    (Status::Infeasible, None, None)
+                } else {
+                    (Status::Unbounded, None, None)
+                }
+            }
+        };
+        let status = if status == Status::Optimal && self.artificial_positive() {
+            Status::Infeasible
+        } else {
+            status
+        };
+
+        let tab = self.tableau.as_ref().unwrap();
+        let primal = tab.current_vertex(self.n_vars);
+        let is_degenerate = self
+            .prev_primal
+            .as_ref()
+            .is_some_and(|prev| *prev == primal);
+        self.prev_primal = Some(primal.clone());
+
+        let step = Step {
+            iteration: self.iteration,
+            primal,
+            objective_value: self.goal.from_minimized(-tab.z_rhs()),
+            status,
+            is_degenerate,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
+            entering_var: entering,
+            leaving_var: leaving,
+            phase: None,
+        };
+        self.last_step = Some(step.clone());
+        step
+    }
+
+    fn last_step(&self) -> Option<&Step<T>> {
+        self.last_step.as_ref()
+    }
+
+    fn tableau(&self) -> Option<&Tableau<T>> {
+        self.tableau.as_ref()
+    }
+
+    fn handle_error(&self, msg: &str) -> Self::Error {
+        msg.to_string()
+    }
+}
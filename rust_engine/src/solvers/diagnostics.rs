@@ -0,0 +1,86 @@
+use crate::model::Problem;
+use crate::solvers::{BlandSimplexSolver, InitSource, Solution, Solver, Status};
+use num_traits::{FromPrimitive, Signed, Zero};
+use std::ops::{AddAssign, Div, MulAssign, SubAssign};
+
+/// Structured explanation of how a solve terminated.
+#[derive(Clone, Debug)]
+pub enum Diagnosis<T> {
+    Optimal(Solution<T>),
+    /// A minimal set of constraint indices that is infeasible on its own:
+    /// dropping any one of them makes the rest feasible.
+    Infeasible { conflicting_constraints: Vec<usize> },
+    /// The objective improves without limit along `direction` (one entry per
+    /// structural variable) as the nonbasic column `entering_var` increases.
+    Unbounded { entering_var: usize, direction: Vec<T> },
+}
+
+/// Solves `problem` with Bland's rule (which cannot cycle) and explains the outcome.
+///
+/// Infeasible problems are reduced to an irreducible conflicting subset with
+/// a deletion filter: each constraint is dropped in turn and kept out if the
+/// remainder is still infeasible.
+pub fn diagnose<T>(problem: &Problem<T>) -> Diagnosis<T>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd
+        + Default,
+{
+    let mut solver = BlandSimplexSolver::new();
+    match solver.solve(InitSource::Problem(problem.clone())) {
+        Err(_) => Diagnosis::Infeasible {
+            conflicting_constraints: conflicting_constraints(problem),
+        },
+        Ok(solution) if solution.status == Status::Unbounded => {
+            let tab = solver.tableau().expect("solver was initialized");
+            let (entering_var, direction) = tab
+                .unbounded_ray()
+                .expect("unbounded tableau has an improving column with no positive entry");
+            Diagnosis::Unbounded { entering_var, direction }
+        }
+        Ok(solution) => Diagnosis::Optimal(solution),
+    }
+}
+
+fn conflicting_constraints<T>(problem: &Problem<T>) -> Vec<usize>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd
+        + Default,
+{
+    let mut kept: Vec<usize> = (0..problem.constraints.len()).collect();
+    let mut k = 0;
+    while k < kept.len() {
+        let mut trial = problem.clone_without_constraints();
+        for (pos, &idx) in kept.iter().enumerate() {
+            if pos != k {
+                trial.constraints.push(problem.constraints[idx].clone());
+            }
+        }
+        let feasible = BlandSimplexSolver::new()
+            .solve_to_feasible(InitSource::Problem(trial))
+            .is_ok();
+        if feasible {
+            k += 1;
+        } else {
+            kept.remove(k);
+        }
+    }
+    kept
+}
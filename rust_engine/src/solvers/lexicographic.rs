@@ -0,0 +1,48 @@
+use crate::model::{Problem, Relation};
+use crate::solvers::{InitSource, Solution, Solver, Status};
+use num_traits::Zero;
+use std::ops::{Mul, Sub};
+
+/// Optimises `problem.lexicographic_objectives` in priority order with
+/// `solver`: after each stage, that objective is pinned to its optimum by an
+/// equality row before the next one is optimised. Falls back to a plain
+/// solve when no lexicographic objectives are set.
+///
+/// The returned `objective` (and `best_bound`) is the primary objective's
+/// value; a non-optimal stage is returned as-is.
+pub fn solve_lexicographic<T, S>(solver: &mut S, problem: &Problem<T>) -> Result<Solution<T>, S::Error>
+where
+    T: Copy + Default + Zero + Sub<Output = T> + Mul<Output = T>,
+    S: Solver<T>,
+{
+    if problem.lexicographic_objectives.is_empty() {
+        return solver.solve(InitSource::Problem(problem.clone()));
+    }
+
+    let mut stage = problem.clone();
+    stage.lexicographic_objectives.clear();
+    let mut solution = None;
+    for (objective, goal) in &problem.lexicographic_objectives {
+        stage.objective = objective.clone();
+        stage.goal = *goal;
+        let sol = solver.solve(InitSource::Problem(stage.clone()))?;
+        if sol.status != Status::Optimal {
+            return Ok(sol);
+        }
+        // The stage row is over the shifted variables, while `sol.objective`
+        // includes the constant that `shift_variable` moves into the objective.
+        let constant = objective
+            .iter()
+            .zip(&stage.variable_offsets)
+            .fold(T::zero(), |acc, (&c, &o)| acc + c * o);
+        stage.add_constraint(objective.clone(), Relation::Equal, sol.objective - constant);
+        solution = Some(sol);
+    }
+
+    let mut solution = solution.expect("at least one stage was solved");
+    let primary = &problem.lexicographic_objectives[0].0;
+    let value = primary.iter().zip(&solution.x).fold(T::zero(), |acc, (&c, &x)| acc + c * x);
+    solution.objective = value;
+    solution.best_bound = Some(value);
+    Ok(solution)
+}
@@ -3,9 +3,939 @@ pub mod simplex_dantzig;
 pub mod simplex_bland;
 pub mod simplex_cycling;
 pub mod shadow_vertex_simplex;
+pub mod diagnostics;
+pub mod lexicographic;
+pub mod revised;
+pub mod big_m;
 
-pub use solver::{InitSource, Solution, Solver, SolveStats, Status, Step};
-pub use simplex_dantzig::SimplexSolver;
+pub use solver::{InitSource, OptimalityCertificate, Phase, PiecewiseLinear, Solution, Solver, SolveHistory, SolveStats, Status, Step, StepIter};
+pub use simplex_dantzig::{SimplexSolver, StepCallback};
 pub use simplex_bland::BlandSimplexSolver;
 pub use simplex_cycling::CyclingProneSolver;
 pub use shadow_vertex_simplex::{ShadowSolveResult, ShadowVertexSimplexSolver};
+pub use diagnostics::{diagnose, Diagnosis};
+pub use lexicographic::solve_lexicographic;
+pub use revised::RevisedSimplexSolver;
+pub use big_m::{BigMPenalty, BigMSimplexSolver};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Goal, Problem, Relation, Sign};
+    use num_rational::Rational64;
+
+    fn rational(n: i64) -> Rational64 {
+        Rational64::from_integer(n)
+    }
+
+    fn sample_problem() -> Problem<Rational64> {
+        let mut prob = Problem::new(vec![rational(3), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(2), rational(1)], Relation::LessEqual, rational(5));
+        prob
+    }
+
+    #[test]
+    fn simplex_tie_break_changes_first_entering_column() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(2));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(3));
+
+        let mut lowest = SimplexSolver::new();
+        let first = lowest.solve_all_steps(InitSource::Problem(prob.clone())).unwrap().next().unwrap();
+        assert_eq!(first.entering_var, Some(0));
+
+        let mut highest = SimplexSolver::new();
+        highest.set_tie_break(crate::model::TieBreak::Highest);
+        let steps: Vec<_> = highest.solve_all_steps(InitSource::Problem(prob)).unwrap().collect();
+        assert_eq!(steps[0].entering_var, Some(1));
+        assert_eq!(steps.last().unwrap().objective_value, rational(5));
+    }
+
+    #[test]
+    fn min_problem_reports_objective_in_its_own_sense() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(2));
+
+        let mut solver = BlandSimplexSolver::new();
+        let last = solver.solve_all_steps(InitSource::Problem(prob)).unwrap().last().unwrap();
+        assert_eq!(last.status, Status::Optimal);
+        assert_eq!(last.objective_value, rational(2));
+    }
+
+    #[test]
+    fn optimal_solution_reports_tight_objective_bounds() {
+        let sol = SimplexSolver::new().solve(InitSource::Problem(sample_problem())).unwrap();
+        let (best, bound) = sol.objective_bounds().unwrap();
+        assert_eq!(best, rational(9));
+        assert!(bound - best >= rational(0));
+
+        let mut unbounded = Problem::new(vec![rational(1)], Goal::Max);
+        unbounded.add_constraint(vec![rational(1)], Relation::GreaterEqual, rational(0));
+        let sol = SimplexSolver::new().solve(InitSource::Problem(unbounded)).unwrap();
+        assert_eq!(sol.status, Status::Unbounded);
+        assert!(sol.objective_bounds().is_none());
+    }
+
+    #[test]
+    fn standard_form_round_trip_preserves_optimum() {
+        let mut prob = Problem::new(vec![rational(2), rational(3)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(4));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(3));
+        prob.add_constraint(vec![rational(1), rational(-1)], Relation::Equal, rational(0));
+
+        let back = Problem::from_standard_form(prob.clone().into_standard_form());
+        assert_eq!(back.objective, prob.objective);
+        assert_eq!(back.goal, Goal::Min);
+        let relations: Vec<_> = back.constraints.iter().map(|c| c.relation.clone()).collect();
+        assert_eq!(relations, vec![Relation::GreaterEqual, Relation::LessEqual, Relation::Equal]);
+
+        let original = BlandSimplexSolver::new().solve(InitSource::Problem(prob)).unwrap();
+        let round_trip = BlandSimplexSolver::new().solve(InitSource::Problem(back)).unwrap();
+        assert_eq!(original.objective, rational(10));
+        assert_eq!(round_trip.objective, original.objective);
+        assert_eq!(round_trip.x, original.x);
+    }
+
+    #[test]
+    fn solve_with_stats_counts_pivots_and_path() {
+        let mut manual = SimplexSolver::new();
+        let pivots = manual
+            .solve_all_steps(InitSource::Problem(sample_problem()))
+            .unwrap()
+            .filter(|s| s.entering_var.is_some())
+            .count();
+
+        let (sol, stats) = SimplexSolver::new().solve_with_stats(InitSource::Problem(sample_problem())).unwrap();
+        assert_eq!(sol.objective, rational(9));
+        assert_eq!(stats.status, Status::Optimal);
+        assert_eq!(stats.total_pivots, pivots);
+        assert_eq!(stats.total_pivots, 2);
+        assert_eq!(stats.degenerate_pivots, 0);
+        // (0, 0), then (5/2, 0), then (1, 3).
+        assert_eq!(stats.path_length, 3);
+    }
+
+    #[test]
+    fn solution_as_named_pairs_values() {
+        let sol = SimplexSolver::new().solve(InitSource::Problem(sample_problem())).unwrap();
+        let named = sol.as_named(&["x0".to_string(), "x1".to_string()]);
+        assert_eq!(named, vec![("x0".to_string(), rational(1)), ("x1".to_string(), rational(3))]);
+    }
+
+    #[test]
+    fn degenerate_vertex_is_flagged() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(1));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(1));
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(2));
+
+        let mut solver = SimplexSolver::new();
+        solver.init(InitSource::Problem(prob));
+        solver.find_initial_bfs().unwrap();
+        assert!(!solver.current_step().degenerate_vertex);
+
+        let steps: Vec<_> = std::iter::from_fn(|| (!solver.is_done()).then(|| solver.step())).collect();
+        let last = steps.last().unwrap();
+        assert_eq!(last.primal, vec![rational(1), rational(1)]);
+        assert!(last.degenerate_vertex);
+        assert!(solver.tableau().unwrap().is_degenerate());
+    }
+
+    #[test]
+    fn feasibility_predicates_track_solve_progress() {
+        let mut solver = BlandSimplexSolver::new();
+        assert!(!solver.is_primal_feasible());
+
+        solver.init(InitSource::Problem(sample_problem()));
+        solver.find_initial_bfs().unwrap();
+        assert!(solver.is_primal_feasible());
+        assert!(!solver.is_dual_feasible());
+
+        solver.step();
+        assert!(solver.is_primal_feasible());
+        assert!(!solver.is_dual_feasible());
+
+        while !solver.is_done() {
+            solver.step();
+        }
+        assert!(solver.is_primal_feasible());
+        assert!(solver.is_dual_feasible());
+    }
+
+    #[test]
+    fn surplus_basis_is_not_primal_feasible_before_phase_one() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(2));
+
+        let mut solver = BlandSimplexSolver::new();
+        solver.init(InitSource::Problem(prob));
+        assert!(!solver.is_primal_feasible());
+        solver.find_initial_bfs().unwrap();
+        assert!(solver.is_primal_feasible());
+    }
+
+    #[test]
+    fn range_constraint_is_a_single_row() {
+        let mut ranged = Problem::new(vec![rational(1), rational(2)], Goal::Min);
+        ranged.add_range_constraint(vec![rational(1), rational(1)], rational(3), rational(7));
+        ranged.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(2));
+        assert_eq!(ranged.constraints.len(), 2);
+        assert_eq!(ranged.constraints[0].range, Some(rational(4)));
+
+        let mut two_rows = Problem::new(vec![rational(1), rational(2)], Goal::Min);
+        two_rows.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(3));
+        two_rows.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(7));
+        two_rows.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(2));
+
+        let sol = BlandSimplexSolver::new().solve(InitSource::Problem(ranged.clone())).unwrap();
+        let expected = BlandSimplexSolver::new().solve(InitSource::Problem(two_rows)).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.objective, rational(4));
+        assert_eq!(sol.objective, expected.objective);
+        assert_eq!(sol.x, vec![rational(2), rational(1)]);
+        assert_eq!(sol.verify_against(&ranged), Ok(()));
+        assert!(!ranged.constraints[0].is_satisfied_by(&[rational(1), rational(1)]));
+
+        let tab = ranged.into_tableau_form();
+        assert_eq!(tab.m, 2);
+        assert_eq!(tab.upper_bound(2), Some(rational(4)));
+        assert_eq!(tab.upper_bound(3), None);
+    }
+
+    #[test]
+    fn slacks_and_reduced_costs_follow_original_rows_and_variables() {
+        let mut prob = Problem::new(vec![rational(1), rational(-1)], Goal::Min);
+        prob.set_variable_sign(0, Sign::Free);
+        prob.set_variable_sign(1, Sign::NonPositive);
+        prob.add_range_constraint(vec![rational(1), rational(0)], rational(-2), rational(3));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::GreaterEqual, rational(-4));
+
+        let mut solver = SimplexSolver::new();
+        let sol = solver.solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(sol.x, vec![rational(-2), rational(0)]);
+
+        let tab = solver.tableau().unwrap();
+        assert_eq!(tab.num_vars(), 5);
+        assert_eq!(tab.slack_values(), vec![rational(5), rational(4)]);
+        assert_eq!(tab.original_reduced_costs(), vec![rational(0), rational(-1)]);
+    }
+
+    #[test]
+    fn range_slack_flips_to_its_bound() {
+        // The slack starts complemented (x - s' = 1); raising it to its
+        // bound of 2 takes x to 3 with no basis change.
+        let mut prob = Problem::new(vec![rational(1)], Goal::Max);
+        prob.add_range_constraint(vec![rational(1)], rational(1), rational(3));
+
+        let mut solver = SimplexSolver::new();
+        let (sol, history) = solver.solve_with_history(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.x, vec![rational(3)]);
+        assert!(history.iter().any(|s| s.entering_var == Some(1) && s.leaving_var.is_none()));
+        assert_eq!(solver.tableau().unwrap().basic_variable_values(), vec![rational(3), rational(0)]);
+    }
+
+    #[test]
+    fn range_slack_leaves_at_its_upper_bound() {
+        let mut ranged = Problem::new(vec![rational(1), rational(2)], Goal::Max);
+        ranged.add_range_constraint(vec![rational(1), rational(-1)], rational(-1), rational(1));
+        ranged.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(3));
+        ranged.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(5));
+
+        let mut two_rows = ranged.clone();
+        two_rows.constraints[0].range = None;
+        two_rows.add_constraint(vec![rational(1), rational(-1)], Relation::GreaterEqual, rational(-1));
+        let expected = SimplexSolver::new().solve(InitSource::Problem(two_rows)).unwrap();
+        assert_eq!(expected.x, vec![rational(3), rational(4)]);
+
+        let solutions = [
+            SimplexSolver::new().solve(InitSource::Problem(ranged.clone())).unwrap(),
+            BlandSimplexSolver::new().solve(InitSource::Problem(ranged.clone())).unwrap(),
+            BigMSimplexSolver::new().solve(InitSource::Problem(ranged.clone())).unwrap(),
+        ];
+        for sol in solutions {
+            assert_eq!(sol.status, Status::Optimal);
+            assert_eq!(sol.x, expected.x);
+            assert_eq!(sol.objective, rational(11));
+        }
+    }
+
+    #[test]
+    fn removing_binding_constraint_changes_optimum() {
+        let mut prob = sample_problem();
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(2));
+        let tight = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(tight.objective, Rational64::new(17, 2));
+
+        let removed = prob.remove_constraint(2);
+        assert_eq!(removed.rhs, rational(2));
+        assert_eq!(prob.constraints.len(), 2);
+        let loose = SimplexSolver::new().solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(loose.objective, rational(9));
+    }
+
+    #[test]
+    fn solve_to_feasible_stops_at_first_feasible_vertex() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(2));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(3));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(3));
+
+        let feasible = BlandSimplexSolver::new().solve_to_feasible(InitSource::Problem(prob.clone())).unwrap();
+        let optimal = BlandSimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(feasible.status, Status::Feasible);
+        assert_ne!(feasible.x, optimal.x);
+        assert!(prob.constraints.iter().all(|c| c.is_satisfied_by(&feasible.x)));
+        assert!(feasible.x.iter().all(|&v| v >= rational(0)));
+
+        let mut infeasible = Problem::new(vec![rational(1)], Goal::Max);
+        infeasible.add_constraint(vec![rational(1)], Relation::GreaterEqual, rational(2));
+        infeasible.add_constraint(vec![rational(1)], Relation::LessEqual, rational(1));
+        assert!(BlandSimplexSolver::new().solve_to_feasible(InitSource::Problem(infeasible)).is_err());
+    }
+
+    #[test]
+    fn big_m_value_makes_penalty_exact() {
+        // min x s.t. x >= 2, written as max -x.
+        let mut prob = Problem::new(vec![rational(-1)], Goal::Max);
+        prob.add_constraint(vec![rational(1)], Relation::GreaterEqual, rational(2));
+
+        // Big-M form: max -x - M*a s.t. x + a >= 2.
+        let penalised = |m: Rational64| {
+            let mut p = Problem::new(vec![rational(-1), -m], Goal::Max);
+            p.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(2));
+            BlandSimplexSolver::new().solve(InitSource::Problem(p)).unwrap()
+        };
+
+        let too_small = penalised(Rational64::new(1, 2));
+        assert_eq!(too_small.x, vec![rational(0), rational(2)]);
+
+        let m = prob.big_m_value().unwrap();
+        assert!(m > rational(1));
+        let exact = penalised(m);
+        assert_eq!(exact.x, vec![rational(2), rational(0)]);
+        assert_eq!(exact.objective, rational(-2));
+    }
+
+    #[test]
+    fn reoptimize_with_objective_warm_starts() {
+        let mut warm = SimplexSolver::new();
+        warm.solve(InitSource::Problem(sample_problem())).unwrap();
+        let before = warm.last_step().unwrap().iteration;
+
+        let last = warm.reoptimize_with_objective(vec![rational(4), rational(3)]);
+        assert_eq!(last.status, Status::Optimal);
+        assert_eq!(last.objective_value, rational(13));
+        assert_eq!(last.primal, vec![rational(1), rational(3)]);
+        let warm_pivots = last.iteration - before;
+
+        let mut changed = sample_problem();
+        changed.objective = vec![rational(4), rational(3)];
+        let (cold, stats) = SimplexSolver::new().solve_with_stats(InitSource::Problem(changed)).unwrap();
+        assert_eq!(cold.objective, rational(13));
+        assert!(warm_pivots < stats.total_pivots);
+    }
+
+    #[test]
+    fn reoptimize_with_objective_prices_free_variables() {
+        let mut p = Problem::new(vec![rational(1)], Goal::Max);
+        p.set_variable_sign(0, Sign::Free);
+        p.add_range_constraint(vec![rational(1)], rational(-5), rational(3));
+        let mut warm = SimplexSolver::new();
+        assert_eq!(warm.solve(InitSource::Problem(p)).unwrap().x, vec![rational(3)]);
+
+        let last = warm.reoptimize_with_objective(vec![rational(-1)]);
+        assert_eq!(last.status, Status::Optimal);
+        assert_eq!(last.primal, vec![rational(-5)]);
+        assert_eq!(last.objective_value, rational(5));
+    }
+
+    #[test]
+    fn reoptimize_with_objective_keeps_the_shift_constant() {
+        let mut p = Problem::new(vec![rational(1)], Goal::Max);
+        p.add_constraint(vec![rational(1)], Relation::LessEqual, rational(10));
+        p.shift_variable(0, rational(2));
+        let mut warm = SimplexSolver::new();
+        assert_eq!(warm.solve(InitSource::Problem(p)).unwrap().objective, rational(10));
+
+        let last = warm.reoptimize_with_objective(vec![rational(1)]);
+        assert_eq!(last.primal, vec![rational(10)]);
+        assert_eq!(last.objective_value, rational(10));
+    }
+
+    #[test]
+    fn certificate_of_optimality_verifies_sample_problem() {
+        let cert = SimplexSolver::new().certificate_of_optimality(&sample_problem()).unwrap();
+        assert_eq!(cert.primal, vec![rational(1), rational(3)]);
+        assert_eq!(cert.dual, vec![rational(1), rational(1)]);
+        assert_eq!(cert.objective, rational(9));
+        assert_eq!(cert.dual_objective, rational(9));
+        assert!(cert.verified);
+    }
+
+    #[test]
+    fn certificate_of_optimality_errors_when_unbounded() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(-1)], Relation::LessEqual, rational(1));
+        assert!(BlandSimplexSolver::new().certificate_of_optimality(&prob).is_err());
+    }
+
+    #[test]
+    fn diagnose_reports_conflicting_constraints() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(3));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(1));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::GreaterEqual, rational(2));
+
+        match diagnose(&prob) {
+            Diagnosis::Infeasible { conflicting_constraints } => assert_eq!(conflicting_constraints, vec![1, 2]),
+            other => panic!("expected infeasible, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn diagnose_reports_unbounded_direction() {
+        let mut prob = Problem::new(vec![rational(1), rational(0)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(-1)], Relation::LessEqual, rational(1));
+
+        match diagnose(&prob) {
+            Diagnosis::Unbounded { entering_var, direction } => {
+                assert_eq!(entering_var, 1);
+                assert_eq!(direction, vec![rational(1), rational(1)]);
+            }
+            other => panic!("expected unbounded, got {:?}", other),
+        }
+        assert!(matches!(diagnose(&sample_problem()), Diagnosis::Optimal(s) if s.objective == rational(9)));
+    }
+
+    #[test]
+    fn lexicographic_optimises_secondary_within_primary_optimum() {
+        // Primary: max x + y is optimal on the whole edge x + y = 4, y <= 3.
+        // Secondary: min x picks the end of that edge where y = 3.
+        let mut prob = Problem::new(vec![rational(0), rational(0)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(3));
+        prob.set_lexicographic_objectives(vec![
+            (vec![rational(1), rational(1)], Goal::Max),
+            (vec![rational(1), rational(0)], Goal::Min),
+        ]);
+
+        let sol = solve_lexicographic(&mut SimplexSolver::new(), &prob).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.x, vec![rational(1), rational(3)]);
+        assert_eq!(sol.objective, rational(4));
+    }
+
+    #[test]
+    fn lexicographic_pins_stages_in_shifted_variables() {
+        let mut prob = Problem::new(vec![rational(0), rational(0)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(3));
+        prob.set_lexicographic_objectives(vec![
+            (vec![rational(1), rational(1)], Goal::Max),
+            (vec![rational(1), rational(0)], Goal::Min),
+        ]);
+        prob.shift_variable(0, rational(1));
+
+        let sol = solve_lexicographic(&mut SimplexSolver::new(), &prob).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.x, vec![rational(1), rational(3)]);
+        assert_eq!(sol.objective, rational(4));
+
+        assert!(RevisedSimplexSolver::new().solve(InitSource::Problem(prob.clone())).is_err());
+        assert!(SimplexSolver::new().certificate_of_optimality(&prob).is_err());
+    }
+
+    #[test]
+    fn parametric_rhs_matches_hand_computation() {
+        // max 3x + 2y, x + y <= b, 2x + y <= 5:
+        // 3b on [0, 5/2], 5 + b on [5/2, 5], then flat at 10.
+        let pl = SimplexSolver::new()
+            .solve_parametric_rhs(&sample_problem(), 0, rational(0), rational(6))
+            .unwrap();
+        assert_eq!(pl.breakpoints, vec![rational(0), Rational64::new(5, 2), rational(5), rational(6)]);
+        assert_eq!(pl.slopes, vec![rational(3), rational(1), rational(0)]);
+        assert_eq!(pl.values, vec![rational(0), Rational64::new(15, 2), rational(10), rational(10)]);
+        assert_eq!(pl.stopped, None);
+    }
+
+    #[test]
+    fn parametric_rhs_stops_when_infeasible() {
+        // min x, x >= b, x <= 3: optimum b until the rows cross at b = 3.
+        let mut prob = Problem::new(vec![rational(1)], Goal::Min);
+        prob.add_constraint(vec![rational(1)], Relation::GreaterEqual, rational(1));
+        prob.add_constraint(vec![rational(1)], Relation::LessEqual, rational(3));
+
+        let pl = BlandSimplexSolver::new().solve_parametric_rhs(&prob, 0, rational(1), rational(5)).unwrap();
+        assert_eq!(pl.breakpoints, vec![rational(1), rational(3)]);
+        assert_eq!(pl.slopes, vec![rational(1)]);
+        assert_eq!(pl.values, vec![rational(1), rational(3)]);
+        assert_eq!(pl.stopped, Some(Status::Infeasible));
+    }
+
+    #[test]
+    fn parametric_rhs_rejects_range_constraints() {
+        let mut prob = Problem::new(vec![rational(1)], Goal::Min);
+        prob.add_range_constraint(vec![rational(1)], rational(2), rational(12));
+        let result = SimplexSolver::new().solve_parametric_rhs(&prob, 0, rational(12), rational(15));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn verify_against_accepts_solution_and_rejects_tampering() {
+        let prob = sample_problem();
+        let sol = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(sol.verify_against(&prob), Ok(()));
+
+        let mut infeasible = sol.clone();
+        infeasible.x[0] = rational(2);
+        assert_eq!(infeasible.verify_against(&prob), Err("Constraint 0 is violated".to_string()));
+
+        let mut wrong_objective = sol.clone();
+        wrong_objective.objective = rational(10);
+        assert!(wrong_objective.verify_against(&prob).is_err());
+    }
+
+    #[test]
+    fn pivot_history_replays_to_final_tableau() {
+        let prob = sample_problem();
+        let mut solver = SimplexSolver::new();
+        solver.solve(InitSource::Problem(prob.clone())).unwrap();
+        let history = solver.pivot_history();
+        assert_eq!(history, vec![(1, 0), (0, 1)]);
+
+        let mut replay = prob.to_tableau();
+        for &(row, col) in &history {
+            replay.pivot(row, col);
+        }
+        let fin = solver.tableau().unwrap();
+        assert_eq!(replay.basis, fin.basis);
+        assert_eq!(replay.data.data, fin.data.data);
+    }
+
+    #[test]
+    fn steepest_edge_reaches_optimum() {
+        let mut solver = SimplexSolver::new();
+        solver.set_pivot_rule(crate::model::PivotRule::SteepestEdge);
+        let sol = solver.solve(InitSource::Problem(sample_problem())).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.x, vec![rational(1), rational(3)]);
+        assert_eq!(sol.objective, rational(9));
+
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(2));
+        let sol = solver.solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(sol.objective, rational(2));
+    }
+
+    #[test]
+    fn bland_pivot_rule_enters_first_negative_column() {
+        let mut prob = Problem::new(vec![rational(1), rational(3)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(4));
+
+        let mut dantzig = SimplexSolver::new();
+        let first = dantzig.solve_all_steps(InitSource::Problem(prob.clone())).unwrap().next().unwrap();
+        assert_eq!(first.entering_var, Some(1));
+
+        let mut bland = SimplexSolver::new();
+        bland.set_pivot_rule(crate::model::PivotRule::Bland);
+        let steps: Vec<_> = bland.solve_all_steps(InitSource::Problem(prob)).unwrap().collect();
+        assert_eq!(steps[0].entering_var, Some(0));
+        assert_eq!(steps.last().unwrap().objective_value, rational(12));
+    }
+
+    #[test]
+    fn current_objective_tracks_last_step() {
+        let mut solver = SimplexSolver::new();
+        assert_eq!(solver.current_objective(), None);
+        solver.init(InitSource::Problem(sample_problem()));
+        solver.find_initial_bfs().unwrap();
+        assert_eq!(solver.current_objective(), Some(rational(0)));
+
+        solver.step();
+        let last = solver.step();
+        assert_eq!(solver.current_objective(), Some(last.objective_value));
+        assert_eq!(solver.current_objective(), Some(rational(9)));
+    }
+
+    #[test]
+    fn revised_simplex_matches_tableau_simplex() {
+        let mut min_prob = Problem::new(vec![rational(2), rational(3)], Goal::Min);
+        min_prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(4));
+        min_prob.add_constraint(vec![rational(1), rational(3)], Relation::GreaterEqual, rational(6));
+
+        let mut eq_prob = Problem::new(vec![rational(1), rational(2), rational(1)], Goal::Max);
+        eq_prob.add_constraint(vec![rational(1), rational(1), rational(1)], Relation::Equal, rational(6));
+        eq_prob.add_constraint(vec![rational(1), rational(-1), rational(0)], Relation::LessEqual, rational(2));
+        eq_prob.add_constraint(vec![rational(0), rational(1), rational(0)], Relation::LessEqual, rational(4));
+
+        for prob in [sample_problem(), min_prob, eq_prob] {
+            let expected = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+            let revised = RevisedSimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+            assert_eq!(revised.status, Status::Optimal);
+            assert_eq!(revised.objective, expected.objective);
+            assert!(revised.verify_against(&prob).is_ok());
+        }
+
+        let sol = RevisedSimplexSolver::new().solve(InitSource::Problem(sample_problem())).unwrap();
+        assert_eq!(sol.x, vec![rational(1), rational(3)]);
+    }
+
+    #[test]
+    fn revised_simplex_reports_infeasible_and_unbounded() {
+        let mut infeasible = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        infeasible.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(1));
+        infeasible.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(3));
+        assert!(RevisedSimplexSolver::new().solve(InitSource::Problem(infeasible)).is_err());
+
+        let mut unbounded = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        unbounded.add_constraint(vec![rational(1), rational(-1)], Relation::LessEqual, rational(1));
+        let sol = RevisedSimplexSolver::new().solve(InitSource::Problem(unbounded)).unwrap();
+        assert_eq!(sol.status, Status::Unbounded);
+    }
+
+    #[test]
+    fn revised_simplex_feasibility_and_unsupported_inputs() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Min);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(2));
+        prob.add_constraint(vec![rational(1), rational(2)], Relation::GreaterEqual, rational(3));
+        let mut solver = RevisedSimplexSolver::new();
+        solver.init(InitSource::Problem(prob));
+        assert!(!solver.is_primal_feasible());
+        solver.find_initial_bfs().unwrap();
+        assert!(solver.is_primal_feasible());
+        while !solver.is_done() {
+            solver.step();
+        }
+        assert!(solver.is_dual_feasible());
+
+        let mut signed = sample_problem();
+        signed.set_variable_sign(0, Sign::Free);
+        assert!(RevisedSimplexSolver::new().solve(InitSource::Problem(signed)).is_err());
+        assert!(RevisedSimplexSolver::new()
+            .solve_parametric_rhs(&sample_problem(), 0, rational(0), rational(6))
+            .is_err());
+    }
+
+    #[test]
+    fn sparse_standard_form_matches_dense() {
+        use crate::linalg::{Matrix, SparseMatrix};
+        use crate::model::StandardForm;
+
+        // Deterministic pseudo-random sparse LP: max c.x s.t. Ax + s = b, A >= 0.
+        let (m, n) = (8, 6);
+        let mut seed: u64 = 12345;
+        let mut next = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) as i64
+        };
+        // Each generated entry goes into the sparse matrix and, separately,
+        // into plain dense rows, so the reference never passes through
+        // SparseMatrix.
+        let mut a = SparseMatrix::new(m, n + m);
+        let mut dense_rows = vec![vec![rational(0); n + m]; m];
+        for (i, row) in dense_rows.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate().take(n) {
+                if next() % 4 == 0 || j == i % n {
+                    let v = rational(1 + next() % 5);
+                    a.set(i, j, v);
+                    *entry = v;
+                }
+            }
+            a.set(i, n + i, rational(1));
+            row[n + i] = rational(1);
+        }
+        let b: Vec<_> = (0..m).map(|_| rational(10 + next() % 10)).collect();
+        let mut c: Vec<_> = (0..n).map(|_| rational(-(1 + next() % 4))).collect();
+        c.resize(n + m, rational(0));
+        let slacks: Vec<usize> = (n..n + m).collect();
+        assert!(a.nnz() < m * (n + m) / 2);
+
+        let sparse = StandardForm::from_sparse(&a, b.clone(), c.clone(), Goal::Min, slacks.clone());
+        let dense = StandardForm::new(Matrix::from_rows(dense_rows).unwrap(), b, c, Goal::Min, slacks);
+        let from_sparse = SimplexSolver::new().solve(InitSource::StandardForm(sparse)).unwrap();
+        let from_dense = SimplexSolver::new().solve(InitSource::StandardForm(dense)).unwrap();
+        assert_eq!(from_sparse.status, Status::Optimal);
+        assert_eq!(from_sparse.x, from_dense.x);
+        assert_eq!(from_sparse.objective, from_dense.objective);
+    }
+
+    #[test]
+    fn solution_reports_optimal_basis() {
+        let sol = SimplexSolver::new().solve(InitSource::Problem(sample_problem())).unwrap();
+        let mut basis = sol.basis.clone();
+        basis.sort();
+        assert_eq!(basis, vec![0, 1]);
+        for (j, v) in sol.x.iter().enumerate() {
+            assert_eq!(*v != rational(0), sol.basis.contains(&j));
+        }
+
+        let mut prob = sample_problem();
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(10));
+        let sol = BlandSimplexSolver::new().solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(sol.basis.len(), 3);
+        assert!(sol.basis.contains(&0) && sol.basis.contains(&1) && sol.basis.contains(&4));
+    }
+
+    #[test]
+    fn on_step_callback_sees_improving_objectives() {
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let mut solver = SimplexSolver::new();
+        solver.on_step(Box::new(move |step, tab| {
+            assert_eq!(step.basis, tab.basis);
+            sink.lock().unwrap().push(step.objective_value);
+            std::ops::ControlFlow::Continue(())
+        }));
+        let sol = solver.solve(InitSource::Problem(sample_problem())).unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), solver.pivot_history().len());
+        assert!(seen.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(*seen.last().unwrap(), sol.objective);
+    }
+
+    #[test]
+    fn on_step_callback_can_stop_the_solve() {
+        let mut solver = SimplexSolver::new();
+        solver.on_step(Box::new(|_, _| std::ops::ControlFlow::Break(())));
+        let sol = solver.solve(InitSource::Problem(sample_problem())).unwrap();
+        assert_eq!(sol.status, Status::Feasible);
+        assert_eq!(solver.pivot_history().len(), 1);
+        assert!(sol.objective < rational(9));
+    }
+
+    #[test]
+    fn shifted_lower_bound_moves_optimum() {
+        // x >= 2 forces the optimum off (1, 3) to (2, 1).
+        let mut prob = sample_problem();
+        prob.shift_variable(0, rational(2));
+        let sol = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.x, vec![rational(2), rational(1)]);
+        assert_eq!(sol.objective, rational(8));
+        assert!(sol.verify_against(&prob).is_ok());
+    }
+
+    #[test]
+    fn objective_cutoff_stops_before_optimum() {
+        let mut solver = SimplexSolver::new();
+        solver.set_objective_cutoff(rational(7));
+        let sol = solver.solve(InitSource::Problem(sample_problem())).unwrap();
+        assert!(solver.cutoff_hit());
+        assert_eq!(sol.status, Status::Feasible);
+        assert_eq!(sol.best_bound, None);
+        assert!(sol.objective > rational(7) && sol.objective < rational(9));
+        assert_eq!(sol.x, vec![Rational64::new(5, 2), rational(0)]);
+
+        solver.set_objective_cutoff(rational(100));
+        let sol = solver.solve(InitSource::Problem(sample_problem())).unwrap();
+        assert!(!solver.cutoff_hit());
+        assert_eq!(sol.objective, rational(9));
+    }
+
+    #[test]
+    fn big_m_matches_two_phase_on_ge_and_eq_rows() {
+        let mut ge = Problem::new(vec![rational(2), rational(3)], Goal::Min);
+        ge.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(4));
+        ge.add_constraint(vec![rational(1), rational(3)], Relation::GreaterEqual, rational(6));
+
+        let mut eq = Problem::new(vec![rational(1), rational(2), rational(1)], Goal::Max);
+        eq.add_constraint(vec![rational(1), rational(1), rational(1)], Relation::Equal, rational(6));
+        eq.add_constraint(vec![rational(1), rational(-1), rational(0)], Relation::LessEqual, rational(2));
+        eq.add_constraint(vec![rational(0), rational(1), rational(0)], Relation::LessEqual, rational(4));
+
+        for prob in [sample_problem(), ge, eq] {
+            // SimplexSolver runs a Phase I before optimising: the two-phase reference.
+            let two_phase = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+            let big_m = BigMSimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+            assert_eq!(big_m.status, Status::Optimal);
+            assert_eq!(big_m.objective, two_phase.objective);
+            assert!(big_m.verify_against(&prob).is_ok());
+        }
+    }
+
+    #[test]
+    fn big_m_reports_infeasible_when_artificial_stays_basic() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::LessEqual, rational(1));
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::GreaterEqual, rational(3));
+        let sol = BigMSimplexSolver::new().solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(sol.status, Status::Infeasible);
+    }
+
+    #[test]
+    fn partial_pricing_matches_full_pricing() {
+        let mut wide = Problem::new((1..=8).map(rational).collect(), Goal::Max);
+        wide.add_constraint(vec![rational(1); 8], Relation::LessEqual, rational(10));
+        wide.add_constraint((1..=8).rev().map(rational).collect(), Relation::LessEqual, rational(20));
+        let mut min_prob = Problem::new(vec![rational(2), rational(3), rational(1)], Goal::Min);
+        min_prob.add_constraint(vec![rational(1), rational(1), rational(1)], Relation::GreaterEqual, rational(4));
+        min_prob.add_constraint(vec![rational(1), rational(0), rational(-1)], Relation::LessEqual, rational(1));
+
+        // (problem, pivots under full pricing, pivots with chunk 2)
+        for (prob, full_pivots, partial_pivots) in [(sample_problem(), 2, 2), (wide, 1, 5), (min_prob, 2, 2)] {
+            let (full, full_stats) = SimplexSolver::new().solve_with_stats(InitSource::Problem(prob.clone())).unwrap();
+            let mut solver = SimplexSolver::new();
+            solver.set_partial_pricing(2);
+            let (partial, partial_stats) = solver.solve_with_stats(InitSource::Problem(prob)).unwrap();
+            assert_eq!(partial.status, Status::Optimal);
+            assert_eq!(partial.objective, full.objective);
+            assert_eq!(full_stats.total_pivots, full_pivots);
+            assert_eq!(partial_stats.total_pivots, partial_pivots);
+        }
+    }
+
+    #[test]
+    fn two_period_block_lp_reaches_known_optimum() {
+        use crate::linalg::Matrix;
+        // Per-period capacity x1 + x2 <= 4 and x3 + x4 <= 3, linked by a
+        // shared limit x1 + x3 <= 5 on the more profitable product.
+        let period = |cap: i64| {
+            let mut block = Matrix::new(0, 2);
+            block.push_row(&[rational(1), rational(1)]);
+            (block, rational(cap))
+        };
+        let (p1, cap1) = period(4);
+        let (p2, cap2) = period(3);
+        let blocks = Matrix::from_diagonal_blocks(&[p1, p2]);
+        assert_eq!((blocks.rows, blocks.cols), (2, 4));
+        assert_eq!(blocks[(0, 2)], rational(0));
+
+        let mut prob = Problem::new(vec![rational(3), rational(2), rational(3), rational(2)], Goal::Max);
+        prob.add_constraint_rows(&blocks, Relation::LessEqual, &[cap1, cap2]);
+        prob.add_constraint(vec![rational(1), rational(0), rational(1), rational(0)], Relation::LessEqual, rational(5));
+
+        let sol = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.objective, rational(19));
+        assert!(sol.verify_against(&prob).is_ok());
+    }
+
+    #[test]
+    fn solution_constructors_set_status_and_x() {
+        let opt = Solution::optimal(vec![rational(1), rational(3)], rational(9));
+        assert_eq!(opt.status, Status::Optimal);
+        assert_eq!(opt.x, vec![rational(1), rational(3)]);
+        assert_eq!(opt.best_bound, Some(rational(9)));
+
+        let inf = Solution::<Rational64>::infeasible();
+        assert_eq!(inf.status, Status::Infeasible);
+        assert!(inf.x.is_empty() && inf.basis.is_empty() && inf.best_bound.is_none());
+
+        let unb = Solution::<Rational64>::unbounded().with_basis(vec![2, 3]);
+        assert_eq!(unb.status, Status::Unbounded);
+        assert!(unb.x.is_empty());
+        assert_eq!(unb.basis, vec![2, 3]);
+    }
+
+    #[test]
+    fn phase_one_handles_zero_rhs_equality() {
+        // max x  s.t.  y - x = 0, x <= 1, y <= 1/2  -> x = y = 1/2
+        let mut prob = Problem::new(vec![rational(1), rational(0)], Goal::Max);
+        prob.add_constraint(vec![rational(-1), rational(1)], Relation::Equal, rational(0));
+        prob.add_constraint(vec![rational(1), rational(0)], Relation::LessEqual, rational(1));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, Rational64::new(1, 2));
+
+        let sol = SimplexSolver::new().solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.x, vec![Rational64::new(1, 2), Rational64::new(1, 2)]);
+        assert_eq!(sol.verify_against(&prob), Ok(()));
+    }
+
+    #[test]
+    fn phase_one_handles_redundant_equality() {
+        let mut prob = Problem::new(vec![rational(1), rational(2)], Goal::Max);
+        prob.add_constraint(vec![rational(1), rational(1)], Relation::Equal, rational(2));
+        prob.add_constraint(vec![rational(2), rational(2)], Relation::Equal, rational(4));
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(1));
+
+        let mut solver = SimplexSolver::new();
+        let sol = solver.solve(InitSource::Problem(prob.clone())).unwrap();
+        assert_eq!(sol.status, Status::Optimal);
+        assert_eq!(sol.objective, rational(3));
+        assert_eq!(sol.verify_against(&prob), Ok(()));
+        assert!(solver.tableau().unwrap().infeasible_basis_rows().is_empty());
+    }
+
+    #[test]
+    fn big_m_value_reports_overflow() {
+        let mut prob = Problem::new(vec![rational(1), rational(1)], Goal::Max);
+        for _ in 0..20 {
+            prob.add_constraint(vec![rational(2), rational(5)], Relation::LessEqual, rational(10));
+        }
+        assert_eq!(prob.big_m_value(), None);
+        assert!(BigMSimplexSolver::new().solve(InitSource::Problem(prob.clone())).is_err());
+
+        let mut solver = BigMSimplexSolver::new();
+        solver.set_penalty(rational(1000));
+        let sol = solver.solve(InitSource::Problem(prob)).unwrap();
+        assert_eq!(sol.objective, rational(5));
+    }
+
+    #[test]
+    fn dual_and_certificate_respect_variable_signs() {
+        // max x, x free, x <= -2.
+        let mut prob = Problem::new(vec![rational(1)], Goal::Max);
+        prob.set_variable_sign(0, Sign::Free);
+        prob.add_constraint(vec![rational(1)], Relation::LessEqual, rational(-2));
+        assert_eq!(prob.dual().constraints[0].relation, Relation::Equal);
+
+        let cert = SimplexSolver::new().certificate_of_optimality(&prob).unwrap();
+        assert!(cert.verified);
+        assert_eq!(cert.objective, rational(-2));
+        assert_eq!(cert.dual_objective, rational(-2));
+    }
+
+    #[test]
+    fn unbounded_ray_is_in_original_variables() {
+        // max -x0, x0 free, x1 <= 1: x0 decreases without bound.
+        let mut prob = Problem::new(vec![rational(-1), rational(0)], Goal::Max);
+        prob.set_variable_sign(0, Sign::Free);
+        prob.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(1));
+        match diagnose(&prob) {
+            Diagnosis::Unbounded { direction, .. } => assert_eq!(direction, vec![rational(-1), rational(0)]),
+            other => panic!("expected unbounded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn big_m_separates_infeasible_from_unbounded() {
+        // max x0: x0 is unbounded, and x1 >= 2 needs an artificial.
+        let mut unbounded = Problem::new(vec![rational(1), rational(0)], Goal::Max);
+        unbounded.add_constraint(vec![rational(0), rational(1)], Relation::GreaterEqual, rational(2));
+        let sol = BigMSimplexSolver::new().solve(InitSource::Problem(unbounded.clone())).unwrap();
+        assert_eq!(sol.status, Status::Unbounded);
+
+        let mut infeasible = unbounded;
+        infeasible.add_constraint(vec![rational(0), rational(1)], Relation::LessEqual, rational(1));
+        let sol = BigMSimplexSolver::new().solve(InitSource::Problem(infeasible)).unwrap();
+        assert_eq!(sol.status, Status::Infeasible);
+    }
+
+    #[test]
+    fn solve_all_steps_matches_solve_with_history() {
+        let (_, history) = SimplexSolver::new().solve_with_history(InitSource::Problem(sample_problem())).unwrap();
+
+        let mut solver = SimplexSolver::new();
+        let steps: Vec<Step<Rational64>> = solver
+            .solve_all_steps(InitSource::Problem(sample_problem()))
+            .unwrap()
+            .collect();
+
+        // `history` starts with the vertex before the first step.
+        assert_eq!(steps.len(), history.len() - 1);
+        for (a, b) in steps.iter().zip(&history[1..]) {
+            assert_eq!(a.iteration, b.iteration);
+            assert_eq!(a.primal, b.primal);
+            assert_eq!(a.status, b.status);
+            assert_eq!(a.basis, b.basis);
+        }
+        let last = steps.last().unwrap();
+        assert_eq!(last.status, Status::Optimal);
+        assert_eq!(last.objective_value, rational(9));
+    }
+}
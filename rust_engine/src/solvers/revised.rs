@@ -0,0 +1,467 @@
+use std::collections::HashSet;
+
+use crate::linalg::Matrix;
+use crate::model::{Goal, PivotResult, Sign, StandardForm};
+use crate::solvers::{InitSource, Solver, Step, Status};
+use num_traits::{FromPrimitive, One, Signed, Zero};
+use std::ops::{AddAssign, Div, MulAssign, SubAssign};
+
+/// Revised simplex solver (Dantzig pivot rule) with cycling detection.
+///
+/// Keeps only the basis inverse `B^-1` and the basic values `x_B`; reduced
+/// costs and the entering column are computed on demand from the original
+/// standard-form data. Rows without a unit slack column get an artificial
+/// variable and a phase I in `find_initial_bfs()`, which falls back to
+/// Bland's rule if Dantzig's rule revisits a basis.
+///
+/// `Problem` sources go through `Problem::into_standard_form`, so a problem
+/// with sign-restricted or shifted variables is rejected by `find_initial_bfs()`.
+/// `tableau()` is always `None`; feasibility is read off `B^-1` and `x_B`
+/// instead, and basis indices refer to standard-form columns.
+pub struct RevisedSimplexSolver<T> {
+    a: Matrix<T>,
+    b: Vec<T>,
+    c: Vec<T>,
+    /// Column indices of the original (non-slack) variables.
+    structural: Vec<usize>,
+    /// Row of each artificial column; artificial `k` is column `a.cols + k`.
+    artificial_rows: Vec<usize>,
+    binv: Matrix<T>,
+    x_b: Vec<T>,
+    basis: Vec<usize>,
+    goal: Goal,
+    iteration: usize,
+    initialized: bool,
+    /// Why the last `init()` source could not be loaded, reported by `find_initial_bfs()`.
+    unsupported: Option<String>,
+    done: bool,
+    last_step: Option<Step<T>>,
+    prev_primal: Option<Vec<T>>,
+    seen_bases: HashSet<Vec<usize>>,
+    pivot_log: Vec<(usize, usize)>,
+}
+
+impl<T> RevisedSimplexSolver<T>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd
+        + Default,
+{
+    pub fn new() -> Self {
+        Self {
+            a: Matrix::new(0, 0),
+            b: Vec::new(),
+            c: Vec::new(),
+            structural: Vec::new(),
+            artificial_rows: Vec::new(),
+            binv: Matrix::new(0, 0),
+            x_b: Vec::new(),
+            basis: Vec::new(),
+            goal: Goal::Max,
+            iteration: 0,
+            initialized: false,
+            unsupported: None,
+            done: false,
+            last_step: None,
+            prev_primal: None,
+            seen_bases: HashSet::new(),
+            pivot_log: Vec::new(),
+        }
+    }
+
+    /// Current basis inverse `B^-1`.
+    pub fn basis_inverse(&self) -> &Matrix<T> {
+        &self.binv
+    }
+
+    fn m(&self) -> usize {
+        self.a.rows
+    }
+
+    /// Column `j` of `[A | I_artificial]`.
+    fn column(&self, j: usize) -> Vec<T> {
+        if j < self.a.cols {
+            (0..self.m()).map(|i| self.a[(i, j)]).collect()
+        } else {
+            let mut col = vec![T::zero(); self.m()];
+            col[self.artificial_rows[j - self.a.cols]] = T::one();
+            col
+        }
+    }
+
+    /// `B^-1 a_j`: the entering column expressed in the current basis.
+    fn ftran(&self, j: usize) -> Vec<T> {
+        let a_j = self.column(j);
+        (0..self.m())
+            .map(|i| (0..self.m()).fold(T::zero(), |acc, k| acc + self.binv[(i, k)] * a_j[k]))
+            .collect()
+    }
+
+    /// Simplex multipliers `y^T = c_B^T B^-1` for the cost vector `cost`.
+    fn duals(&self, cost: &[T]) -> Vec<T> {
+        (0..self.m())
+            .map(|k| {
+                self.basis
+                    .iter()
+                    .enumerate()
+                    .fold(T::zero(), |acc, (i, &j)| acc + cost[j] * self.binv[(i, k)])
+            })
+            .collect()
+    }
+
+    /// Most negative reduced cost `c_j - y^T a_j` among nonbasic columns
+    /// `< allowed`, or with `bland` the lowest-index negative one.
+    fn entering(&self, cost: &[T], allowed: usize, bland: bool) -> Option<usize> {
+        let y = self.duals(cost);
+        let mut best: Option<(usize, T)> = None;
+        for j in (0..allowed).filter(|j| !self.basis.contains(j)) {
+            let a_j = self.column(j);
+            let d = y.iter().zip(&a_j).fold(cost[j], |acc, (&yi, &aij)| acc - yi * aij);
+            if d < T::zero() && best.is_none_or(|(_, v)| d < v) {
+                best = Some((j, d));
+                if bland {
+                    break;
+                }
+            }
+        }
+        best.map(|(j, _)| j)
+    }
+
+    /// Minimum-ratio test on `d = B^-1 a_j`; ties go to the lowest row, or
+    /// with `bland` to the lowest basic column index.
+    fn ratio_test(&self, d: &[T], bland: bool) -> Option<usize> {
+        let mut best: Option<(usize, T)> = None;
+        for (i, &di) in d.iter().enumerate() {
+            if di > T::zero() {
+                let ratio = self.x_b[i] / di;
+                let better = best.is_none_or(|(b, r)| {
+                    ratio < r || (bland && ratio == r && self.basis[i] < self.basis[b])
+                });
+                if better {
+                    best = Some((i, ratio));
+                }
+            }
+        }
+        best.map(|(i, _)| i)
+    }
+
+    fn find_pivot(&self, cost: &[T], allowed: usize, bland: bool) -> (PivotResult, Vec<T>) {
+        match self.entering(cost, allowed, bland) {
+            None => (PivotResult::Optimal, Vec::new()),
+            Some(col) => {
+                let d = self.ftran(col);
+                match self.ratio_test(&d, bland) {
+                    Some(row) => (PivotResult::Pivot(row, col), d),
+                    None => (PivotResult::Unbounded, d),
+                }
+            }
+        }
+    }
+
+    /// Replaces the basic variable of `row` by column `col`, where `d = B^-1 a_col`.
+    fn pivot(&mut self, row: usize, col: usize, d: &[T]) {
+        let m = self.m();
+        let p = d[row];
+        for k in 0..m {
+            self.binv[(row, k)] = self.binv[(row, k)] / p;
+        }
+        self.x_b[row] = self.x_b[row] / p;
+        for (i, &factor) in d.iter().enumerate() {
+            if i == row || factor.is_zero() {
+                continue;
+            }
+            for k in 0..m {
+                let delta = factor * self.binv[(row, k)];
+                self.binv[(i, k)] -= delta;
+            }
+            let delta = factor * self.x_b[row];
+            self.x_b[i] -= delta;
+        }
+        self.basis[row] = col;
+        self.pivot_log.push((row, col));
+    }
+
+    fn phase_two_cost(&self) -> Vec<T> {
+        let mut cost = self.c.clone();
+        cost.resize(self.a.cols + self.artificial_rows.len(), T::zero());
+        cost
+    }
+
+    /// Value of every column of `[A | I_artificial]` at the current basis.
+    fn full_solution(&self) -> Vec<T> {
+        let mut x = vec![T::zero(); self.a.cols + self.artificial_rows.len()];
+        for (i, &j) in self.basis.iter().enumerate() {
+            x[j] = self.x_b[i];
+        }
+        x
+    }
+
+    fn primal(&self) -> Vec<T> {
+        let x = self.full_solution();
+        self.structural.iter().map(|&j| x[j]).collect()
+    }
+
+    fn objective(&self) -> T {
+        let cost = self.phase_two_cost();
+        let z = self
+            .basis
+            .iter()
+            .zip(&self.x_b)
+            .fold(T::zero(), |acc, (&j, &v)| acc + cost[j] * v);
+        self.goal.from_minimized(z)
+    }
+
+    /// Pivots basic artificials at zero onto any real column with a nonzero
+    /// entry in their row; artificials left behind sit on redundant rows.
+    fn drive_out_artificials(&mut self) {
+        for row in 0..self.m() {
+            if self.basis[row] < self.a.cols {
+                continue;
+            }
+            let entering = (0..self.a.cols)
+                .filter(|j| !self.basis.contains(j))
+                .map(|j| (j, self.ftran(j)))
+                .find(|(_, d)| !d[row].is_zero());
+            if let Some((col, d)) = entering {
+                self.pivot(row, col, &d);
+            }
+        }
+    }
+}
+
+impl<T> Default for RevisedSimplexSolver<T>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd
+        + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Solver<T> for RevisedSimplexSolver<T>
+where
+    T: Zero
+        + Signed
+        + Clone
+        + Copy
+        + FromPrimitive
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + Div<Output = T>
+        + PartialOrd
+        + Default,
+{
+    type Error = String;
+
+    fn init(&mut self, source: InitSource<T>) {
+        self.unsupported = None;
+        let sf: StandardForm<T> = match source {
+            InitSource::Problem(p) if p.variable_signs.iter().any(|&s| s != Sign::NonNegative) || p.is_shifted() => {
+                self.unsupported = Some("Revised simplex requires non-negative, unshifted variables".to_string());
+                self.initialized = false;
+                return;
+            }
+            InitSource::Problem(p) => p.into_standard_form(),
+            InitSource::StandardForm(sf) => sf,
+        };
+        self.goal = sf.goal;
+        self.structural = (0..sf.a.cols).filter(|j| !sf.slack_indices.contains(j)).collect();
+        self.a = sf.a;
+        self.b = sf.b;
+        self.c = sf.c;
+        for i in 0..self.m() {
+            if self.b[i] < T::zero() {
+                self.b[i] = -self.b[i];
+                for j in 0..self.a.cols {
+                    self.a[(i, j)] = -self.a[(i, j)];
+                }
+            }
+        }
+
+        let m = self.m();
+        self.artificial_rows = Vec::new();
+        self.basis = Vec::with_capacity(m);
+        for i in 0..m {
+            let unit = (0..self.a.cols).find(|&j| {
+                !self.basis.contains(&j)
+                    && (0..m).all(|r| if r == i { self.a[(r, j)].is_one() } else { self.a[(r, j)].is_zero() })
+            });
+            match unit {
+                Some(j) => self.basis.push(j),
+                None => {
+                    self.basis.push(self.a.cols + self.artificial_rows.len());
+                    self.artificial_rows.push(i);
+                }
+            }
+        }
+        self.binv = Matrix::identity(m);
+        self.x_b = self.b.clone();
+
+        self.iteration = 0;
+        self.initialized = true;
+        self.done = false;
+        self.last_step = None;
+        self.prev_primal = None;
+        self.seen_bases = HashSet::new();
+        self.pivot_log = Vec::new();
+    }
+
+    fn find_initial_bfs(&mut self) -> Result<bool, Self::Error> {
+        if let Some(msg) = &self.unsupported {
+            return Err(msg.clone());
+        }
+        if !self.artificial_rows.is_empty() {
+            let total = self.a.cols + self.artificial_rows.len();
+            let mut cost = vec![T::zero(); total];
+            for v in cost.iter_mut().skip(self.a.cols) {
+                *v = T::one();
+            }
+            let mut phase_one_bases = HashSet::from([self.basis.clone()]);
+            let mut bland = false;
+            while let (PivotResult::Pivot(row, col), d) = self.find_pivot(&cost, total, bland) {
+                self.pivot(row, col, &d);
+                // A repeated basis means Dantzig's rule is cycling; Bland's cannot.
+                if !phase_one_bases.insert(self.basis.clone()) {
+                    bland = true;
+                }
+            }
+            let infeasibility = self
+                .basis
+                .iter()
+                .zip(&self.x_b)
+                .filter(|(j, _)| **j >= self.a.cols)
+                .fold(T::zero(), |acc, (_, &v)| acc + v);
+            if infeasibility > T::zero() {
+                return Err("Infeasible: Phase I found no feasible basis".to_string());
+            }
+            self.drive_out_artificials();
+        }
+        self.seen_bases.insert(self.basis.clone());
+        Ok(true)
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn current_step(&self) -> Step<T> {
+        Step {
+            iteration: self.iteration,
+            primal: self.primal(),
+            objective_value: self.objective(),
+            status: if self.done { Status::Optimal } else { Status::InProgress },
+            is_degenerate: false,
+            degenerate_vertex: self.x_b.iter().any(|v| v.is_zero()),
+            basis: self.basis.clone(),
+            entering_var: None,
+            leaving_var: None,
+            phase: None,
+        }
+    }
+
+    fn step(&mut self) -> Step<T> {
+        let cost = self.phase_two_cost();
+        let (status, entering, leaving) = match self.find_pivot(&cost, self.a.cols, false) {
+            (PivotResult::Pivot(row, col), d) => {
+                let leaving_var = self.basis[row];
+                self.pivot(row, col, &d);
+                self.iteration += 1;
+
+                if self.seen_bases.contains(&self.basis) {
+                    self.done = true;
+                    (Status::Cycling, Some(col), Some(leaving_var))
+                } else {
+                    self.seen_bases.insert(self.basis.clone());
+                    (Status::InProgress, Some(col), Some(leaving_var))
+                }
+            }
+            (PivotResult::Optimal, _) => {
+                self.done = true;
+                (Status::Optimal, None, None)
+            }
+            (PivotResult::Unbounded, _) => {
+                self.done = true;
+                (Status::Unbounded, None, None)
+            }
+            (PivotResult::BoundFlip(_), _) => unreachable!("standard form has no bounded columns"),
+        };
+
+        let primal = self.primal();
+        let is_degenerate = self
+            .prev_primal
+            .as_ref()
+            .is_some_and(|prev| *prev == primal);
+        self.prev_primal = Some(primal.clone());
+
+        let step = Step {
+            iteration: self.iteration,
+            primal,
+            objective_value: self.objective(),
+            status,
+            is_degenerate,
+            degenerate_vertex: self.x_b.iter().any(|v| v.is_zero()),
+            basis: self.basis.clone(),
+            entering_var: entering,
+            leaving_var: leaving,
+            phase: None,
+        };
+        self.last_step = Some(step.clone());
+        step
+    }
+
+    fn last_step(&self) -> Option<&Step<T>> {
+        self.last_step.as_ref()
+    }
+
+    fn current_objective(&self) -> Option<T> {
+        self.initialized.then(|| self.objective())
+    }
+
+    /// No basic value is negative and no artificial is basic at a positive value.
+    fn is_primal_feasible(&self) -> bool
+    where
+        T: PartialOrd + Zero + One + Copy,
+    {
+        self.initialized
+            && self
+                .basis
+                .iter()
+                .zip(&self.x_b)
+                .all(|(&j, &v)| v >= T::zero() && (j < self.a.cols || v.is_zero()))
+    }
+
+    /// No nonbasic real column has a negative phase II reduced cost.
+    fn is_dual_feasible(&self) -> bool
+    where
+        T: PartialOrd + Zero,
+    {
+        self.initialized && self.entering(&self.phase_two_cost(), self.a.cols, false).is_none()
+    }
+
+    fn pivot_history(&self) -> Vec<(usize, usize)> {
+        self.pivot_log.clone()
+    }
+
+    fn handle_error(&self, msg: &str) -> Self::Error {
+        msg.to_string()
+    }
+}
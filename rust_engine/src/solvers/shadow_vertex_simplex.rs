@@ -1,34 +1,48 @@
 use crate::model::tableau_form::Tableau;
+use crate::model::Goal;
 use crate::model::PivotResult;
-use crate::solvers::{InitSource, Solution, Solver, Step, Status};
+use crate::solvers::{InitSource, Phase, Solution, Solver, Step, Status};
 use num_traits::{One, Signed, Zero};
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 
 /// Types that support a numerically safe "strictly positive" check for the shadow pivot.
-/// For exact types (e.g. `Rational64`) this is `self > 0`; for floats use a small epsilon
-/// so that `denom <= 0` is not triggered by rounding error.
+/// For exact types (e.g. `Rational64`) the default tolerance is zero; for floats it is
+/// a small epsilon so that `denom <= 0` is not triggered by rounding error.
+/// `ShadowVertexSimplexSolver::set_tolerance` overrides the default per solver.
 pub trait EpsilonThreshold: Zero + PartialOrd + Copy {
-    fn is_strictly_positive(self) -> bool;
+    /// Largest value still treated as zero by default.
+    fn default_tolerance() -> Self;
+
+    #[inline]
+    fn is_strictly_positive(self) -> bool {
+        self.exceeds(Self::default_tolerance())
+    }
+
+    /// True when `self` is strictly above `tolerance`.
+    #[inline]
+    fn exceeds(self, tolerance: Self) -> bool {
+        self > tolerance
+    }
 }
 
 impl EpsilonThreshold for num_rational::Rational64 {
     #[inline]
-    fn is_strictly_positive(self) -> bool {
-        self > num_rational::Rational64::zero()
+    fn default_tolerance() -> Self {
+        num_rational::Rational64::zero()
     }
 }
 
 impl EpsilonThreshold for f64 {
     #[inline]
-    fn is_strictly_positive(self) -> bool {
-        self > f64::EPSILON
+    fn default_tolerance() -> Self {
+        f64::EPSILON
     }
 }
 
 impl EpsilonThreshold for f32 {
     #[inline]
-    fn is_strictly_positive(self) -> bool {
-        self > f32::EPSILON
+    fn default_tolerance() -> Self {
+        f32::EPSILON
     }
 }
 
@@ -41,6 +55,7 @@ pub struct ShadowSolveResult<T> {
 
 pub struct ShadowVertexSimplexSolver<T> {
     tableau: Option<Tableau<T>>,
+    goal: Goal,
     n_vars: usize,
     iteration: usize,
     done: bool,
@@ -52,6 +67,8 @@ pub struct ShadowVertexSimplexSolver<T> {
     /// True objective coefficients (stored for z-row restoration and reduced costs).
     c: Vec<T>,
     c_rhs: T,
+    /// Values at or below this are treated as zero by the pivot rule.
+    tolerance: T,
 }
 
 impl<T> ShadowVertexSimplexSolver<T>
@@ -74,6 +91,7 @@ where
     pub fn new() -> Self {
         Self {
             tableau: None,
+            goal: Goal::Max,
             n_vars: 0,
             iteration: 0,
             done: false,
@@ -83,9 +101,17 @@ where
             d_rhs: T::zero(),
             c: Vec::new(),
             c_rhs: T::zero(),
+            tolerance: T::default_tolerance(),
         }
     }
 
+    /// Overrides the zero tolerance of the pivot rule (default
+    /// `EpsilonThreshold::default_tolerance`). Loosen it for ill-conditioned
+    /// float LPs where rounding leaves tiny nonzero reduced costs.
+    pub fn set_tolerance(&mut self, tolerance: T) {
+        self.tolerance = tolerance;
+    }
+
     /// Sets the auxiliary objective `d`. Call after `init()` and before `find_initial_bfs()`.
     /// `d_coeffs` has length n (structural), `d_slack` has length m.
     /// They are concatenated into a single vector internally.
@@ -99,6 +125,12 @@ where
         self.d_rhs = d_rhs;
     }
 
+    /// Number of Phase II pivots taken since `init()`; Phase I (towards the
+    /// d-optimal vertex) is not counted. Use this to compare pivot rules.
+    pub fn iteration_count(&self) -> usize {
+        self.iteration
+    }
+
     /// Returns (d'x, c'x) at the current vertex for plotting the shadow polygon.
     fn current_shadow_point(&self) -> (T, T) {
         let tab = self.tableau.as_ref().unwrap();
@@ -135,29 +167,8 @@ where
             }
         }
 
-        let solution = match last_step.status {
-            Status::Optimal => Solution {
-                x: last_step.primal,
-                objective: last_step.objective_value,
-                status: Status::Optimal,
-            },
-            Status::Infeasible => Solution {
-                x: vec![],
-                objective: T::default(),
-                status: Status::Infeasible,
-            },
-            Status::Unbounded => Solution {
-                x: vec![],
-                objective: T::default(),
-                status: Status::Unbounded,
-            },
-            Status::Cycling => Solution {
-                x: last_step.primal,
-                objective: last_step.objective_value,
-                status: Status::Cycling,
-            },
-            Status::InProgress => return Err(self.handle_error("Solver stopped prematurely")),
-        };
+        let solution = Solution::from_terminal_step(last_step)
+            .ok_or_else(|| self.handle_error("Solver stopped prematurely"))?;
 
         Ok(ShadowSolveResult {
             solution,
@@ -176,7 +187,7 @@ where
     ///
     /// A variable j becomes a pivot candidate when bar_w_j crosses from
     /// <= 0 to > 0 as lambda increases.
-    fn find_shadow_pivot_col(r_d: &[T], r_c: &[T]) -> Option<usize> {
+    fn find_shadow_pivot_col(r_d: &[T], r_c: &[T], tolerance: T) -> Option<usize> {
         let mut best_col = None;
         let mut best_lambda: Option<T> = None;
         let mut must_enter_col: Option<usize> = None;
@@ -189,18 +200,18 @@ where
 
             let denom = r_d[j] + r_c[j];
 
-            if (-denom).is_strictly_positive() {
+            if (-denom).exceeds(tolerance) {
                 let lambda_j = r_d[j] / denom;
 
                 if best_lambda.is_none() || lambda_j < best_lambda.unwrap() {
                     best_lambda = Some(lambda_j);
                     best_col = Some(j);
                 }
-            } else if r_d[j].is_strictly_positive() {
-                if must_enter_rc.is_none() || r_c[j] < must_enter_rc.unwrap() {
-                    must_enter_rc = Some(r_c[j]);
-                    must_enter_col = Some(j);
-                }
+            } else if r_d[j].exceeds(tolerance)
+                && (must_enter_rc.is_none() || r_c[j] < must_enter_rc.unwrap())
+            {
+                must_enter_rc = Some(r_c[j]);
+                must_enter_col = Some(j);
             }
         }
 
@@ -216,15 +227,12 @@ where
         let r_d = tab.reduced_costs(&self.d);
         let r_c = tab.z_row_vars();
 
-        let col = match Self::find_shadow_pivot_col(&r_d, &r_c) {
+        let col = match Self::find_shadow_pivot_col(&r_d, &r_c, self.tolerance) {
             Some(c) => c,
             None => return PivotResult::Optimal,
         };
 
-        match tab.ratio_test(col) {
-            Some(row) => PivotResult::Pivot(row, col),
-            None => PivotResult::Unbounded,
-        }
+        tab.pivot_or_flip(col, tab.ratio_test(col))
     }
 }
 
@@ -271,6 +279,7 @@ where
     type Error = String;
 
     fn init(&mut self, source: InitSource<T>) {
+        self.goal = source.goal();
         let (n_vars, tableau) = source.into_tableau_and_n_vars();
         self.n_vars = n_vars;
 
@@ -289,9 +298,12 @@ where
     }
 
     fn find_initial_bfs(&mut self) -> Result<bool, Self::Error> {
-        if self.tableau.as_ref().map_or(false, |t| t.has_negative_rhs()) {
+        if self.tableau.as_ref().is_some_and(|t| t.has_negative_rhs()) {
             return Err("Infeasible: initial tableau has negative RHS".to_string());
         }
+        if !self.tableau.as_mut().unwrap().restore_feasible_basis() {
+            return Err("Infeasible: Phase I found no feasible basis".to_string());
+        }
 
         // Phase I: install -d as z-row and pivot to a d-optimal BFS.
         let neg_d: Vec<T> = self.d.iter().map(|&x| -x).collect();
@@ -307,6 +319,9 @@ where
                 PivotResult::Pivot(row, col) => {
                     self.tableau.as_mut().unwrap().pivot(row, col);
                 }
+                PivotResult::BoundFlip(col) => {
+                    self.tableau.as_mut().unwrap().flip_bound(col);
+                }
             }
         }
 
@@ -328,11 +343,14 @@ where
         Step {
             iteration: self.iteration,
             primal: tab.current_vertex(self.n_vars),
-            objective_value: tab.z_rhs(),
+            objective_value: self.goal.from_minimized(-tab.z_rhs()),
             status: if self.done { Status::Optimal } else { Status::InProgress },
             is_degenerate: false,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
             entering_var: None,
             leaving_var: None,
+            phase: Some(if self.iteration == 0 { Phase::Auxiliary } else { Phase::Objective }),
         }
     }
 
@@ -344,6 +362,11 @@ where
                 self.iteration += 1;
                 (Status::InProgress, Some(col), Some(leaving_var))
             }
+            PivotResult::BoundFlip(col) => {
+                self.tableau.as_mut().unwrap().flip_bound(col);
+                self.iteration += 1;
+                (Status::InProgress, Some(col), None)
+            }
             PivotResult::Optimal => {
                 self.done = true;
                 (Status::Optimal, None, None)
@@ -359,17 +382,20 @@ where
         let is_degenerate = self
             .prev_primal
             .as_ref()
-            .map_or(false, |prev| *prev == primal);
+            .is_some_and(|prev| *prev == primal);
         self.prev_primal = Some(primal.clone());
 
         let step = Step {
             iteration: self.iteration,
             primal,
-            objective_value: tab.z_rhs(),
+            objective_value: self.goal.from_minimized(-tab.z_rhs()),
             status,
             is_degenerate,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
             entering_var: entering,
             leaving_var: leaving,
+            phase: Some(Phase::Objective),
         };
         self.last_step = Some(step.clone());
         step
@@ -379,6 +405,10 @@ where
         self.last_step.as_ref()
     }
 
+    fn tableau(&self) -> Option<&Tableau<T>> {
+        self.tableau.as_ref()
+    }
+
     fn handle_error(&self, msg: &str) -> Self::Error {
         msg.to_string()
     }
@@ -409,6 +439,19 @@ mod tests {
         assert_eq!(sol.objective, rational(9, 1));
     }
 
+    #[test]
+    fn shadow_pivot_tolerance_ignores_rounding_noise() {
+        // Column 0 has true reduced costs of zero polluted by rounding; column 1
+        // is the genuine improving pivot.
+        let r_d = [1e-12, -1.0];
+        let r_c = [-1e-12, -1.0];
+
+        let tight = ShadowVertexSimplexSolver::<f64>::find_shadow_pivot_col(&r_d, &r_c, f64::EPSILON);
+        assert_eq!(tight, Some(0));
+        let loose = ShadowVertexSimplexSolver::<f64>::find_shadow_pivot_col(&r_d, &r_c, 1e-9);
+        assert_eq!(loose, Some(1));
+    }
+
     #[test]
     fn shadow_vertex_with_d_zero_matches_standard_behavior() {
         let mut prob = Problem::new(vec![rational(1, 1), rational(1, 1)], Goal::Min);
@@ -536,6 +579,65 @@ mod tests {
         );
     }
 
+    fn klee_minty(n: usize) -> Problem<Rational64> {
+        let c = (0..n).map(|j| rational(1 << (n - 1 - j), 1)).collect();
+        let mut prob = Problem::new(c, Goal::Max);
+        for i in 0..n {
+            let mut row: Vec<Rational64> = (0..i).map(|j| rational(1 << (i - j + 1), 1)).collect();
+            row.push(rational(1, 1));
+            row.resize(n, rational(0, 1));
+            prob.add_constraint(row, Relation::LessEqual, rational(5i64.pow(i as u32), 1));
+        }
+        prob
+    }
+
+    #[test]
+    fn shadow_vertex_iteration_count_vs_dantzig() {
+        for n in 2..=6 {
+            let prob = klee_minty(n);
+            let (dantzig, stats) = crate::solvers::SimplexSolver::new()
+                .solve_with_stats(InitSource::Problem(prob.clone()))
+                .expect("solve");
+
+            let mut shadow = ShadowVertexSimplexSolver::new();
+            let mut d = vec![rational(0, 1); n];
+            d[n - 1] = rational(-1, 1);
+            shadow.set_auxiliary_objective(d, vec![rational(0, 1); n], rational(0, 1));
+            let sol = shadow.solve(InitSource::Problem(prob)).expect("solve");
+
+            assert_eq!(sol.objective, dantzig.objective);
+            assert_eq!(stats.total_pivots, (1 << n) - 1);
+            assert!(shadow.iteration_count() > 0);
+            assert!(
+                shadow.iteration_count() <= stats.total_pivots,
+                "n={}: shadow took {} pivots, Dantzig {}",
+                n, shadow.iteration_count(), stats.total_pivots
+            );
+            if n >= 4 {
+                assert!(shadow.iteration_count() < stats.total_pivots);
+            }
+        }
+    }
+
+    #[test]
+    fn shadow_vertex_steps_report_phase() {
+        let mut prob = Problem::new(vec![rational(1, 1), rational(1, 1)], Goal::Max);
+        for i in 0..2 {
+            let mut row = vec![rational(0, 1); 2];
+            row[i] = rational(1, 1);
+            prob.add_constraint(row, Relation::LessEqual, rational(1, 1));
+        }
+
+        let mut solver = ShadowVertexSimplexSolver::new();
+        solver.set_auxiliary_objective(vec![rational(1, 1), rational(0, 1)], vec![rational(0, 1); 2], rational(0, 1));
+        let result = solver.solve_with_shadow_history(InitSource::Problem(prob)).expect("solve");
+
+        assert_eq!(result.history[0].primal, vec![rational(1, 1), rational(0, 1)]);
+        assert_eq!(result.history[0].phase, Some(Phase::Auxiliary));
+        assert!(result.history[1..].iter().all(|s| s.phase == Some(Phase::Objective)));
+        assert!(result.history.len() > 1);
+    }
+
     #[test]
     fn shadow_vertex_perturbed_3d_cube() {
         let mut prob = Problem::new(
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use crate::model::tableau_form::Tableau;
+use crate::model::Goal;
 use crate::model::PivotResult;
 use crate::solvers::{InitSource, Solver, Step, Status};
 use num_traits::{Signed, Zero, FromPrimitive};
@@ -12,6 +13,7 @@ use std::ops::{AddAssign, Div, MulAssign, SubAssign};
 /// on degenerate LPs.  Cycling is detected via basis-history tracking.
 pub struct CyclingProneSolver<T> {
     tableau: Option<Tableau<T>>,
+    goal: Goal,
     iteration: usize,
     n_vars: usize,
     done: bool,
@@ -36,6 +38,7 @@ where
     pub fn new() -> Self {
         Self {
             tableau: None,
+            goal: Goal::Max,
             iteration: 0,
             n_vars: 0,
             done: false,
@@ -81,6 +84,7 @@ where
     type Error = String;
 
     fn init(&mut self, source: InitSource<T>) {
+        self.goal = source.goal();
         let (n_vars, tableau) = source.into_tableau_and_n_vars();
         self.n_vars = n_vars;
         self.tableau = Some(tableau);
@@ -95,10 +99,13 @@ where
         if self
             .tableau
             .as_ref()
-            .map_or(false, |t| t.has_negative_rhs())
+            .is_some_and(|t| t.has_negative_rhs())
         {
             return Err("Infeasible: initial tableau has negative RHS".to_string());
         }
+        if !self.tableau.as_mut().unwrap().restore_feasible_basis() {
+            return Err("Infeasible: Phase I found no feasible basis".to_string());
+        }
         let tab = self.tableau.as_ref().unwrap();
         self.seen_bases.insert(tab.basis.clone());
         Ok(true)
@@ -113,11 +120,14 @@ where
         Step {
             iteration: self.iteration,
             primal: tab.current_vertex(self.n_vars),
-            objective_value: tab.z_rhs(),
+            objective_value: self.goal.from_minimized(-tab.z_rhs()),
             status: if self.done { Status::Optimal } else { Status::InProgress },
             is_degenerate: false,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
             entering_var: None,
             leaving_var: None,
+            phase: None,
         }
     }
 
@@ -138,6 +148,15 @@ where
                     (Status::InProgress, Some(col), Some(leaving_var))
                 }
             }
+            PivotResult::BoundFlip(col) => {
+                tab.flip_bound(col);
+                self.iteration += 1;
+                // The flip moves to a new vertex without a basis change, so
+                // earlier bases may legitimately come back.
+                self.seen_bases.clear();
+                self.seen_bases.insert(tab.basis.clone());
+                (Status::InProgress, Some(col), None)
+            }
             PivotResult::Optimal => {
                 self.done = true;
                 (Status::Optimal, None, None)
@@ -153,17 +172,20 @@ where
         let is_degenerate = self
             .prev_primal
             .as_ref()
-            .map_or(false, |prev| *prev == primal);
+            .is_some_and(|prev| *prev == primal);
         self.prev_primal = Some(primal.clone());
 
         let step = Step {
             iteration: self.iteration,
             primal,
-            objective_value: tab.z_rhs(),
+            objective_value: self.goal.from_minimized(-tab.z_rhs()),
             status,
             is_degenerate,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
             entering_var: entering,
             leaving_var: leaving,
+            phase: None,
         };
         self.last_step = Some(step.clone());
         step
@@ -173,6 +195,10 @@ where
         self.last_step.as_ref()
     }
 
+    fn tableau(&self) -> Option<&Tableau<T>> {
+        self.tableau.as_ref()
+    }
+
     fn handle_error(&self, msg: &str) -> Self::Error {
         msg.to_string()
     }
@@ -1,22 +1,39 @@
 use std::collections::HashSet;
+use std::ops::ControlFlow;
 
 use crate::model::tableau_form::Tableau;
-use crate::model::PivotResult;
+use crate::model::problem::substitute_signs;
+use crate::model::Goal;
+use crate::model::{PivotResult, PivotRule, TieBreak};
 use crate::solvers::{InitSource, Solver, Step, Status};
 use num_traits::{Signed, Zero, FromPrimitive};
 use std::ops::{AddAssign, Div, MulAssign, SubAssign};
 
-/// Simplex solver (Dantzig pivot rule) with cycling detection.
+/// Simplex solver (Dantzig pivot rule by default; see `set_pivot_rule`) with
+/// cycling detection.
 pub struct SimplexSolver<T> {
     tableau: Option<Tableau<T>>,
+    goal: Goal,
     iteration: usize,
     n_vars: usize,
     done: bool,
     last_step: Option<Step<T>>,
     prev_primal: Option<Vec<T>>,
     seen_bases: HashSet<Vec<usize>>,
+    tie_break: TieBreak,
+    pivot_rule: PivotRule,
+    on_step: Option<StepCallback<T>>,
+    objective_cutoff: Option<T>,
+    cutoff_hit: bool,
+    partial_pricing: Option<usize>,
+    /// Column where the next partial-pricing scan begins.
+    pricing_start: usize,
 }
 
+/// Observer invoked by `SimplexSolver::step` after each pivot; returning
+/// `ControlFlow::Break(())` stops the solve at that step.
+pub type StepCallback<T> = Box<dyn FnMut(&Step<T>, &Tableau<T>) -> ControlFlow<()> + Send + Sync>;
+
 impl<T> SimplexSolver<T>
 where
     T: Zero
@@ -33,12 +50,94 @@ where
     pub fn new() -> Self {
         Self {
             tableau: None,
+            goal: Goal::Max,
             iteration: 0,
             n_vars: 0,
             done: false,
             last_step: None,
             prev_primal: None,
             seen_bases: HashSet::new(),
+            tie_break: TieBreak::default(),
+            pivot_rule: PivotRule::default(),
+            on_step: None,
+            objective_cutoff: None,
+            cutoff_hit: false,
+            partial_pricing: None,
+            pricing_start: 0,
+        }
+    }
+
+    /// Stops as soon as a pivot reaches an objective at least as good as
+    /// `cutoff` (`>=` for max, `<=` for min). That step is reported as
+    /// `Status::Feasible`, since optimality was not proven; `cutoff_hit()`
+    /// tells it apart from a stop requested by the step callback.
+    pub fn set_objective_cutoff(&mut self, cutoff: T) {
+        self.objective_cutoff = Some(cutoff);
+    }
+
+    /// Whether the last run stopped at the objective cutoff rather than at an optimum.
+    pub fn cutoff_hit(&self) -> bool {
+        self.cutoff_hit
+    }
+
+    /// Registers `f` to be called with the step and the tableau after every
+    /// pivot, replacing any earlier callback. No callback is set by default.
+    /// If `f` breaks, the solver is done and that step is reported as
+    /// `Status::Feasible`.
+    pub fn on_step(&mut self, f: StepCallback<T>) {
+        self.on_step = Some(f);
+    }
+
+    /// Under the Dantzig rule, prices only a `chunk`-wide window of columns
+    /// per iteration, moving on to the next window only when the current one
+    /// has no improving column. Each scan resumes after the last entering
+    /// column, so the windows rotate through the full set.
+    pub fn set_partial_pricing(&mut self, chunk: usize) {
+        assert!(chunk > 0, "Partial pricing chunk must be positive");
+        self.partial_pricing = Some(chunk);
+    }
+
+    /// Chooses the entering-column rule; defaults to `PivotRule::Dantzig`.
+    /// The tie-break only applies to the Dantzig rule.
+    pub fn set_pivot_rule(&mut self, rule: PivotRule) {
+        self.pivot_rule = rule;
+    }
+
+    /// Chooses which column enters when several share the most negative reduced cost.
+    pub fn set_tie_break(&mut self, tie_break: TieBreak) {
+        self.tie_break = tie_break;
+    }
+
+    /// Warm start after an objective change: rebuilds the z-row for `objective`
+    /// (one coefficient per original variable, in the problem's goal sense)
+    /// at the current basis and pivots on to the new terminal step. Sign
+    /// substitutions and shifts carry over as in `Problem::into_tableau_form`.
+    pub fn reoptimize_with_objective(&mut self, objective: Vec<T>) -> Step<T>
+    where
+        T: Default,
+    {
+        assert_eq!(objective.len(), self.n_vars, "Objective length must match number of variables");
+        let tab = self.tableau.as_mut().expect("reoptimize_with_objective requires init()");
+
+        let cost: Vec<T> = objective.into_iter().map(|v| self.goal.from_minimized(v)).collect();
+        let constant = cost
+            .iter()
+            .zip(&tab.var_offsets)
+            .fold(T::zero(), |acc, (&c, &o)| acc + c * o);
+        let mut cost = substitute_signs(&tab.var_signs, cost);
+        cost.resize(tab.num_vars(), T::zero());
+        let reduced = tab.reduced_costs(&cost);
+        let z_rhs = -constant - tab.eval_at_basis(&cost);
+        tab.set_z_row(&reduced, z_rhs);
+
+        self.done = false;
+        self.seen_bases.clear();
+        self.seen_bases.insert(tab.basis.clone());
+        loop {
+            let step = self.step();
+            if self.is_done() {
+                return step;
+            }
         }
     }
 }
@@ -78,6 +177,7 @@ where
     type Error = String;
 
     fn init(&mut self, source: InitSource<T>) {
+        self.goal = source.goal();
         let (n_vars, tableau) = source.into_tableau_and_n_vars();
         self.n_vars = n_vars;
         self.tableau = Some(tableau);
@@ -86,16 +186,21 @@ where
         self.last_step = None;
         self.prev_primal = None;
         self.seen_bases = HashSet::new();
+        self.cutoff_hit = false;
+        self.pricing_start = 0;
     }
 
     fn find_initial_bfs(&mut self) -> Result<bool, Self::Error> {
         if self
             .tableau
             .as_ref()
-            .map_or(false, |t| t.has_negative_rhs())
+            .is_some_and(|t| t.has_negative_rhs())
         {
             return Err("Infeasible: initial tableau has negative RHS".to_string());
         }
+        if !self.tableau.as_mut().unwrap().restore_feasible_basis() {
+            return Err("Infeasible: Phase I found no feasible basis".to_string());
+        }
         let tab = self.tableau.as_ref().unwrap();
         self.seen_bases.insert(tab.basis.clone());
         Ok(true)
@@ -110,22 +215,32 @@ where
         Step {
             iteration: self.iteration,
             primal: tab.current_vertex(self.n_vars),
-            objective_value: tab.z_rhs(),
+            objective_value: self.goal.from_minimized(-tab.z_rhs()),
             status: if self.done { Status::Optimal } else { Status::InProgress },
             is_degenerate: false,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
             entering_var: None,
             leaving_var: None,
+            phase: None,
         }
     }
 
     fn step(&mut self) -> Step<T> {
         let tab = self.tableau.as_mut().unwrap();
 
-        let (status, entering, leaving) = match tab.find_pivot_indices() {
+        let pivot = match (self.pivot_rule, self.partial_pricing) {
+            (PivotRule::Dantzig, Some(chunk)) => tab.find_pivot_indices_partial(self.pricing_start, chunk),
+            (PivotRule::Dantzig, None) => tab.find_pivot_indices_with(self.tie_break),
+            (PivotRule::Bland, _) => tab.find_pivot_indices_bland(),
+            (PivotRule::SteepestEdge, _) => tab.find_pivot_indices_steepest_edge(),
+        };
+        let (mut status, entering, leaving) = match pivot {
             PivotResult::Pivot(row, col) => {
                 let leaving_var = tab.basis[row];
                 tab.pivot(row, col);
                 self.iteration += 1;
+                self.pricing_start = (col + 1) % tab.num_vars();
 
                 if self.seen_bases.contains(&tab.basis) {
                     self.done = true;
@@ -135,6 +250,16 @@ where
                     (Status::InProgress, Some(col), Some(leaving_var))
                 }
             }
+            PivotResult::BoundFlip(col) => {
+                tab.flip_bound(col);
+                self.iteration += 1;
+                self.pricing_start = (col + 1) % tab.num_vars();
+                // The flip moves to a new vertex without a basis change, so
+                // earlier bases may legitimately come back.
+                self.seen_bases.clear();
+                self.seen_bases.insert(tab.basis.clone());
+                (Status::InProgress, Some(col), None)
+            }
             PivotResult::Optimal => {
                 self.done = true;
                 (Status::Optimal, None, None)
@@ -150,18 +275,44 @@ where
         let is_degenerate = self
             .prev_primal
             .as_ref()
-            .map_or(false, |prev| *prev == primal);
+            .is_some_and(|prev| *prev == primal);
         self.prev_primal = Some(primal.clone());
 
-        let step = Step {
+        let objective_value = self.goal.from_minimized(-tab.z_rhs());
+        if status == Status::InProgress
+            && let Some(cutoff) = self.objective_cutoff
+        {
+            let reached = match self.goal {
+                Goal::Max => objective_value >= cutoff,
+                Goal::Min => objective_value <= cutoff,
+            };
+            if reached {
+                self.done = true;
+                self.cutoff_hit = true;
+                status = Status::Feasible;
+            }
+        }
+
+        let mut step = Step {
             iteration: self.iteration,
             primal,
-            objective_value: tab.z_rhs(),
+            objective_value,
             status,
             is_degenerate,
+            degenerate_vertex: tab.is_degenerate(),
+            basis: tab.basis.clone(),
             entering_var: entering,
             leaving_var: leaving,
+            phase: None,
         };
+        if entering.is_some()
+            && let Some(f) = self.on_step.as_mut()
+            && f(&step, tab).is_break()
+            && step.status == Status::InProgress
+        {
+            self.done = true;
+            step.status = Status::Feasible;
+        }
         self.last_step = Some(step.clone());
         step
     }
@@ -170,6 +321,10 @@ where
         self.last_step.as_ref()
     }
 
+    fn tableau(&self) -> Option<&Tableau<T>> {
+        self.tableau.as_ref()
+    }
+
     fn handle_error(&self, msg: &str) -> Self::Error {
         msg.to_string()
     }
@@ -1,6 +1,8 @@
-use crate::model::{Problem, StandardForm, Tableau};
+use crate::model::{Goal, Problem, Relation, Sign, StandardForm, Tableau};
 use num_traits::{One, Zero};
-use std::ops::Neg;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+use std::ops::{AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Input for solver init: a Problem or a StandardForm.
 #[derive(Clone)]
@@ -9,6 +11,16 @@ pub enum InitSource<T> {
     StandardForm(StandardForm<T>),
 }
 
+impl<T> InitSource<T> {
+    /// Optimisation sense of the source problem.
+    pub fn goal(&self) -> Goal {
+        match self {
+            InitSource::Problem(p) => p.goal,
+            InitSource::StandardForm(sf) => sf.goal,
+        }
+    }
+}
+
 impl<T> InitSource<T>
 where
     T: Clone + Copy + Default + PartialOrd + One + Zero + Neg<Output = T>,
@@ -33,49 +45,235 @@ where
 /// One solver step: iteration index, primal point, objective value, status,
 /// and per-pivot diagnostics.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Step<T> {
     pub iteration: usize,
     pub primal: Vec<T>,
     pub objective_value: T,
     pub status: Status,
+    /// The pivot into this step left the primal point unchanged.
     pub is_degenerate: bool,
+    /// The vertex reached has a basic variable at zero.
+    pub degenerate_vertex: bool,
+    /// Basic variable of each constraint row at this step.
+    pub basis: Vec<usize>,
     pub entering_var: Option<usize>,
     pub leaving_var: Option<usize>,
+    /// Phase that produced this step, for solvers that run in phases.
+    pub phase: Option<Phase>,
 }
 
-/// Final solution: primal x, objective value, status.
+/// Phase of a two-phase solve: optimising the auxiliary objective `d`, then
+/// the true objective `c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Phase {
+    Auxiliary,
+    Objective,
+}
+
+/// Final solution: primal x, objective value, status, and the best proven
+/// bound on the objective (equal to `objective` for an optimal LP).
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Solution<T> {
     pub x: Vec<T>,
     pub objective: T,
     pub status: Status,
+    pub best_bound: Option<T>,
+    /// Basic column of each constraint row at termination; empty when
+    /// infeasible. Usable to warm-start or inspect the active set.
+    pub basis: Vec<usize>,
+}
+
+impl<T: Clone> Solution<T> {
+    /// Best objective found and best proven bound, when a bound is known.
+    /// Their difference is the optimality gap.
+    pub fn objective_bounds(&self) -> Option<(T, T)> {
+        self.best_bound.clone().map(|bound| (self.objective.clone(), bound))
+    }
+
+    /// Pairs each primal value with its name; `names` must match `x` in length.
+    pub fn as_named(&self, names: &[String]) -> Vec<(String, T)> {
+        assert_eq!(names.len(), self.x.len(), "Names length must match number of variables");
+        names.iter().cloned().zip(self.x.iter().cloned()).collect()
+    }
+}
+
+impl<T: Clone + Default> Solution<T> {
+    /// Optimal solution at `x`; the bound equals the objective. Basis is
+    /// empty until set with `with_basis`.
+    pub fn optimal(x: Vec<T>, objective: T) -> Self {
+        Solution { x, best_bound: Some(objective.clone()), objective, status: Status::Optimal, basis: vec![] }
+    }
+
+    /// Infeasible result: no point, no bound, no basis.
+    pub fn infeasible() -> Self {
+        Solution { x: vec![], objective: T::default(), status: Status::Infeasible, best_bound: None, basis: vec![] }
+    }
+
+    /// Unbounded result: no point and no bound.
+    pub fn unbounded() -> Self {
+        Solution { x: vec![], objective: T::default(), status: Status::Unbounded, best_bound: None, basis: vec![] }
+    }
+
+    /// Sets the terminal basis.
+    pub fn with_basis(mut self, basis: Vec<usize>) -> Self {
+        self.basis = basis;
+        self
+    }
+
+    /// Solution reported for the terminal step of a run; `None` when the
+    /// step is not terminal (`InProgress`). A `Feasible` step, where the run
+    /// was stopped early, keeps its point but proves no bound.
+    pub fn from_terminal_step(step: Step<T>) -> Option<Self> {
+        let solution = match step.status {
+            Status::Optimal => Solution::optimal(step.primal, step.objective_value).with_basis(step.basis),
+            Status::Infeasible => Solution::infeasible(),
+            Status::Unbounded => Solution::unbounded().with_basis(step.basis),
+            Status::Cycling | Status::Feasible => Solution {
+                x: step.primal,
+                objective: step.objective_value,
+                status: step.status,
+                best_bound: None,
+                basis: step.basis,
+            },
+            Status::InProgress => return None,
+        };
+        Some(solution)
+    }
+}
+
+/// Primal and dual optima of an LP together with an exact check that they
+/// certify each other; see `Solver::certificate_of_optimality`.
+#[derive(Clone, Debug)]
+pub struct OptimalityCertificate<T> {
+    pub primal: Vec<T>,
+    /// One value per row of the canonical dual (equalities count twice).
+    pub dual: Vec<T>,
+    pub objective: T,
+    pub dual_objective: T,
+    /// Objectives agree and complementary slackness holds on every row and column.
+    pub verified: bool,
+}
+
+impl<T> Solution<T>
+where
+    T: Copy + PartialOrd + Zero + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Checks in exact arithmetic that `x` satisfies every constraint and sign
+    /// restriction of `problem` and that `objective` equals `c.x`. Shifted
+    /// variables (`Problem::shift_variable`) are checked in shifted form.
+    pub fn verify_against(&self, problem: &Problem<T>) -> Result<(), String> {
+        if self.x.len() != problem.objective.len() {
+            return Err(format!(
+                "Solution has {} values for {} variables",
+                self.x.len(),
+                problem.objective.len()
+            ));
+        }
+        let y: Vec<T> = self
+            .x
+            .iter()
+            .enumerate()
+            .map(|(j, &v)| problem.variable_offsets.get(j).map_or(v, |&o| v - o))
+            .collect();
+        for (i, c) in problem.constraints.iter().enumerate() {
+            if !c.is_satisfied_by(&y) {
+                return Err(format!("Constraint {} is violated", i));
+            }
+        }
+        for (j, (&v, sign)) in y.iter().zip(&problem.variable_signs).enumerate() {
+            let ok = match sign {
+                Sign::NonNegative => v >= T::zero(),
+                Sign::NonPositive => v <= T::zero(),
+                Sign::Free => true,
+            };
+            if !ok {
+                return Err(format!("Variable {} violates its sign restriction", j));
+            }
+        }
+        let value = problem.objective.iter().zip(&self.x).fold(T::zero(), |acc, (&c, &v)| acc + c * v);
+        if value != self.objective {
+            return Err("Reported objective does not equal c.x".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Optimal objective as a piecewise-linear function of one parameter:
+/// `slopes[i]` holds on `[breakpoints[i], breakpoints[i+1]]` and `values[i]`
+/// is the optimum at `breakpoints[i]`.
+#[derive(Clone, Debug)]
+pub struct PiecewiseLinear<T> {
+    pub breakpoints: Vec<T>,
+    pub values: Vec<T>,
+    pub slopes: Vec<T>,
+    /// Why the sweep ended before the upper end of the range, e.g.
+    /// `Status::Infeasible`; `None` when the whole range was covered.
+    pub stopped: Option<Status>,
 }
 
 /// Solver termination status.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Status {
+    #[default]
     InProgress,
     Optimal,
     Infeasible,
     Unbounded,
     Cycling,
+    /// A feasible vertex was reached; optimality was not checked.
+    Feasible,
 }
 
 /// Aggregate statistics from a complete solve run.
 #[derive(Clone, Debug, Default)]
 pub struct SolveStats {
+    /// Steps that changed the tableau (a pivot or a bound flip).
     pub total_pivots: usize,
+    /// Pivots that left the vertex unchanged.
     pub degenerate_pivots: usize,
+    /// Distinct consecutive vertices visited, the starting one included.
     pub path_length: usize,
     pub cycling_detected: bool,
+    /// Final status of the run.
+    pub status: Status,
+    /// Wall-clock time from init to the terminal step.
+    pub elapsed: Duration,
 }
 
-impl Default for Status {
-    fn default() -> Self {
-        Status::InProgress
+impl SolveStats {
+    /// Statistics of a run given as the starting vertex followed by every
+    /// step taken, as from `Solver::solve_with_history`. `elapsed` is left zero.
+    pub fn from_steps<T: PartialEq>(steps: &[Step<T>]) -> Self {
+        let mut stats = SolveStats { path_length: steps.len().min(1), ..Default::default() };
+        for pair in steps.windows(2) {
+            stats.record(&pair[0].primal, &pair[1]);
+        }
+        stats
+    }
+
+    /// Accounts for `step`, taken from the vertex `prev_primal`.
+    fn record<T: PartialEq>(&mut self, prev_primal: &[T], step: &Step<T>) {
+        if step.entering_var.is_some() {
+            self.total_pivots += 1;
+            if step.is_degenerate {
+                self.degenerate_pivots += 1;
+            }
+        }
+        if step.primal != prev_primal {
+            self.path_length += 1;
+        }
+        self.status = step.status;
+        self.cycling_detected = step.status == Status::Cycling;
     }
 }
 
+/// Final solution and every step of a run; see `Solver::solve_with_history`.
+pub type SolveHistory<T> = (Solution<T>, Vec<Step<T>>);
+
 /// Solver trait: init, find_initial_bfs(), step(), last_step(), solve().
 pub trait Solver<T> {
     type Error;
@@ -101,10 +299,46 @@ pub trait Solver<T> {
         None
     }
 
+    /// Returns the current tableau, if the solver has been initialized.
+    fn tableau(&self) -> Option<&Tableau<T>> {
+        None
+    }
+
+    /// Objective value at the current basis, in the problem's own goal
+    /// convention (same as `Step::objective_value`). `None` before `init()`.
+    fn current_objective(&self) -> Option<T> {
+        self.tableau().map(|_| self.current_step().objective_value)
+    }
+
+    /// Pivots performed on the current tableau since `init()`, as `(row, col)`.
+    fn pivot_history(&self) -> Vec<(usize, usize)> {
+        self.tableau().map_or_else(Vec::new, |t| t.pivot_history().to_vec())
+    }
+
+    /// True when every row's basic column is a unit column and no RHS entry
+    /// is negative, i.e. the basis is a genuine BFS. False before `init()`.
+    fn is_primal_feasible(&self) -> bool
+    where
+        T: PartialOrd + Zero + One + Copy,
+    {
+        self.tableau()
+            .is_some_and(|t| !t.has_negative_rhs() && t.infeasible_basis_rows().is_empty())
+    }
+
+    /// True when no reduced cost in the current z-row is negative, i.e. the
+    /// basis is optimal if it is also primal feasible. False before `init()`.
+    fn is_dual_feasible(&self) -> bool
+    where
+        T: PartialOrd + Zero,
+    {
+        self.tableau()
+            .is_some_and(|t| (0..t.num_vars()).all(|j| t[(t.m, j)] >= T::zero()))
+    }
+
     /// Runs to completion: init, find_initial_bfs(), then step until done.
     fn solve(&mut self, source: InitSource<T>) -> Result<Solution<T>, Self::Error>
     where
-        T: Default,
+        T: Clone + Default,
     {
         self.init(source);
         self.find_initial_bfs()?;
@@ -114,30 +348,242 @@ pub trait Solver<T> {
                 break s;
             }
         };
-        match last_step.status {
-            Status::Optimal => Ok(Solution {
-                x: last_step.primal,
-                objective: last_step.objective_value,
-                status: Status::Optimal,
-            }),
-            Status::Infeasible => Ok(Solution {
-                x: vec![],
-                objective: T::default(),
-                status: Status::Infeasible,
-            }),
-            Status::Unbounded => Ok(Solution {
-                x: vec![],
-                objective: T::default(),
-                status: Status::Unbounded,
-            }),
-            Status::Cycling => Ok(Solution {
-                x: last_step.primal,
-                objective: last_step.objective_value,
-                status: Status::Cycling,
-            }),
-            Status::InProgress => Err(self.handle_error("Solver stopped prematurely")),
+        Solution::from_terminal_step(last_step).ok_or_else(|| self.handle_error("Solver stopped prematurely"))
+    }
+
+    /// Like `solve`, also reporting pivot counts, final status and wall-clock time.
+    fn solve_with_stats(&mut self, source: InitSource<T>) -> Result<(Solution<T>, SolveStats), Self::Error>
+    where
+        T: Clone + Default + PartialEq,
+    {
+        let start = Instant::now();
+        self.init(source);
+        self.find_initial_bfs()?;
+        let mut stats = SolveStats { path_length: 1, ..Default::default() };
+        let mut prev_primal = self.current_step().primal;
+        let last_step = loop {
+            let s = self.step();
+            stats.record(&prev_primal, &s);
+            if self.is_done() {
+                break s;
+            }
+            prev_primal = s.primal.clone();
+        };
+        stats.elapsed = start.elapsed();
+
+        let solution = Solution::from_terminal_step(last_step)
+            .ok_or_else(|| self.handle_error("Solver stopped prematurely"))?;
+        Ok((solution, stats))
+    }
+
+    /// Like `solve`, also returning the starting vertex after
+    /// `find_initial_bfs()` followed by every step taken, the terminal one included.
+    fn solve_with_history(&mut self, source: InitSource<T>) -> Result<SolveHistory<T>, Self::Error>
+    where
+        T: Clone + Default,
+    {
+        self.init(source);
+        self.find_initial_bfs()?;
+        let mut history = vec![self.current_step()];
+        loop {
+            history.push(self.step());
+            if self.is_done() {
+                break;
+            }
+        }
+        let last = history.last().cloned().expect("history holds the terminal step");
+        let solution = Solution::from_terminal_step(last)
+            .ok_or_else(|| self.handle_error("Solver stopped prematurely"))?;
+        Ok((solution, history))
+    }
+
+    /// Runs only up to the first primal-feasible basis (init and
+    /// find_initial_bfs()) and returns that vertex with status `Feasible`.
+    /// Infeasibility surfaces as the error from find_initial_bfs().
+    fn solve_to_feasible(&mut self, source: InitSource<T>) -> Result<Solution<T>, Self::Error> {
+        self.init(source);
+        self.find_initial_bfs()?;
+        let step = self.current_step();
+        Ok(Solution {
+            x: step.primal,
+            objective: step.objective_value,
+            status: Status::Feasible,
+            best_bound: None,
+            basis: step.basis,
+        })
+    }
+
+    /// Solves `problem` and its dual (`Problem::dual`) with this solver and
+    /// checks strong duality and complementary slackness exactly. Errors if
+    /// either side does not reach `Optimal`.
+    fn certificate_of_optimality(&mut self, problem: &Problem<T>) -> Result<OptimalityCertificate<T>, Self::Error>
+    where
+        T: Copy + Default + Zero + PartialEq + Neg<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        if problem.is_shifted() {
+            return Err(self.handle_error("Certificate does not support shifted variables"));
+        }
+        let dual_problem = problem.dual();
+        let primal = self.solve(InitSource::Problem(problem.clone()))?;
+        let dual = self.solve(InitSource::Problem(dual_problem.clone()))?;
+        if primal.status != Status::Optimal || dual.status != Status::Optimal {
+            return Err(self.handle_error("Certificate requires both primal and dual to be optimal"));
+        }
+
+        // Dual constraint j is column j of the canonical primal rows, and the
+        // dual objective is their right-hand side.
+        let a = |k: usize, j: usize| dual_problem.constraints[j].coefficient(k);
+        let rows_slack = dual_problem.objective.iter().enumerate().all(|(k, &b_k)| {
+            let ax = (0..primal.x.len()).fold(T::zero(), |acc, j| acc + a(k, j) * primal.x[j]);
+            (dual.x[k] * (b_k - ax)).is_zero()
+        });
+        let cols_slack = problem.objective.iter().enumerate().all(|(j, &c_j)| {
+            let ay = (0..dual.x.len()).fold(T::zero(), |acc, k| acc + a(k, j) * dual.x[k]);
+            (primal.x[j] * (ay - c_j)).is_zero()
+        });
+
+        Ok(OptimalityCertificate {
+            verified: primal.objective == dual.objective && rows_slack && cols_slack,
+            primal: primal.x,
+            dual: dual.x,
+            objective: primal.objective,
+            dual_objective: dual.objective,
+        })
+    }
+
+    /// Optimal objective of `problem` as the RHS of inequality `constraint`
+    /// sweeps `[lo, hi]`. Solves once at `lo`, then follows the basis inverse
+    /// (the constraint's slack column) and takes a dual simplex pivot at each
+    /// breakpoint. Stops early, setting `stopped`, if the LP becomes infeasible.
+    /// Problems with range constraints are rejected: the dual simplex steps do
+    /// not handle bounded slacks.
+    fn solve_parametric_rhs(
+        &mut self,
+        problem: &Problem<T>,
+        constraint: usize,
+        lo: T,
+        hi: T,
+    ) -> Result<PiecewiseLinear<T>, Self::Error>
+    where
+        T: Copy
+            + Default
+            + Zero
+            + One
+            + PartialOrd
+            + Neg<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + AddAssign
+            + SubAssign
+            + MulAssign,
+    {
+        assert!(lo <= hi, "Lower end of the range must not exceed the upper end");
+        let relation = &problem.constraints[constraint].relation;
+        if *relation == Relation::Equal {
+            return Err(self.handle_error("Parametric RHS requires an inequality constraint"));
+        }
+        if problem.constraints.iter().any(|c| c.range.is_some()) {
+            return Err(self.handle_error("Parametric RHS does not support range constraints"));
+        }
+        // Slack column k holds B^-1 times its original +-e_k, which is +e_k
+        // per unit of RHS for `<=` rows and -e_k for `>=` rows.
+        let sign = if *relation == Relation::LessEqual { T::one() } else { -T::one() };
+
+        let mut start = problem.clone();
+        start.constraints[constraint].rhs = lo;
+        let solution = self.solve(InitSource::Problem(start))?;
+        if solution.status != Status::Optimal {
+            return Err(self.handle_error("Parametric RHS requires an optimal solution at the lower end"));
+        }
+        let Some(tab) = self.tableau() else {
+            return Err(self.handle_error("Parametric RHS requires a tableau-based solver"));
+        };
+        let mut tab = tab.clone();
+        let (m, rhs_col, slack_col) = (tab.m, tab.rhs_col(), tab.n + constraint);
+        let value = |tab: &Tableau<T>| problem.goal.from_minimized(-tab.z_rhs());
+
+        let mut result = PiecewiseLinear {
+            breakpoints: vec![lo],
+            values: vec![value(&tab)],
+            slopes: Vec::new(),
+            stopped: None,
+        };
+        let mut t = lo;
+        while t < hi {
+            let rate: Vec<T> = (0..=m).map(|i| sign * tab[(i, slack_col)]).collect();
+            let blocking = (0..m)
+                .filter(|&i| rate[i] < T::zero())
+                .map(|i| (tab.rhs(i) / -rate[i], i))
+                .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            let step = match blocking {
+                Some((step, _)) if t + step < hi => step,
+                _ => hi - t,
+            };
+
+            if step > T::zero() {
+                for (i, &r) in rate.iter().enumerate() {
+                    tab[(i, rhs_col)] += step * r;
+                }
+                t += step;
+                result.slopes.push(problem.goal.from_minimized(-rate[m]));
+                result.breakpoints.push(t);
+                result.values.push(value(&tab));
+            }
+            let Some((_, row)) = blocking.filter(|_| t < hi) else { break };
+
+            // Dual ratio test keeps the z-row non-negative.
+            let entering = (0..tab.num_vars())
+                .filter(|&j| tab[(row, j)] < T::zero())
+                .min_by(|&a, &b| {
+                    let ra = tab[(m, a)] / -tab[(row, a)];
+                    let rb = tab[(m, b)] / -tab[(row, b)];
+                    ra.partial_cmp(&rb).unwrap()
+                });
+            match entering {
+                Some(col) => tab.pivot(row, col),
+                None => {
+                    result.stopped = Some(Status::Infeasible);
+                    break;
+                }
+            }
         }
+        Ok(result)
+    }
+
+    /// Initializes from `source` and returns an iterator yielding one `Step`
+    /// per `next()` until the solver is done (the terminal step included).
+    fn solve_all_steps(&mut self, source: InitSource<T>) -> Result<StepIter<'_, Self, T>, Self::Error>
+    where
+        Self: Sized,
+    {
+        self.init(source);
+        self.find_initial_bfs()?;
+        Ok(StepIter { solver: self, finished: false, _marker: PhantomData })
     }
 
     fn handle_error(&self, msg: &str) -> Self::Error;
 }
+
+/// Lazy iterator over solver steps; see `Solver::solve_all_steps`.
+pub struct StepIter<'a, S, T> {
+    solver: &'a mut S,
+    finished: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<S, T> Iterator for StepIter<'_, S, T>
+where
+    S: Solver<T>,
+{
+    type Item = Step<T>;
+
+    fn next(&mut self) -> Option<Step<T>> {
+        if self.finished {
+            return None;
+        }
+        let step = self.solver.step();
+        self.finished = self.solver.is_done();
+        Some(step)
+    }
+}
@@ -0,0 +1,123 @@
+"""Tests for building PyProblem instances from Python data."""
+
+import unittest
+import linprog_core
+
+
+class TestFromDense(unittest.TestCase):
+
+    def test_3x2_lp_solves(self):
+        prob = linprog_core.PyProblem.from_dense(
+            [3.0, 2.0],
+            [[1.0, 1.0], [2.0, 1.0], [1.0, 0.0]],
+            ["<=", "<=", "<="],
+            [4.0, 5.0, 2.0],
+            goal="max",
+        )
+        sol = linprog_core.PySimplexSolver().solve(prob)
+        self.assertEqual(sol.status, "optimal")
+        self.assertEqual(sol.objective, 9.0)
+        self.assertEqual(sol.x, [1.0, 3.0])
+
+    def test_mismatched_lengths_raise(self):
+        with self.assertRaises(ValueError):
+            linprog_core.PyProblem.from_dense(
+                [1.0, 1.0], [[1.0, 1.0]], ["<=", "<="], [1.0])
+
+    def test_wrong_row_width_raises(self):
+        with self.assertRaises(ValueError):
+            linprog_core.PyProblem.from_dense(
+                [1.0, 1.0], [[1.0, 1.0, 1.0]], ["<="], [1.0])
+
+
+class TestGoal(unittest.TestCase):
+
+    def test_accepts_long_spellings(self):
+        prob = linprog_core.PyProblem([1.0], goal="Minimize")
+        prob.add_constraint([1.0], ">=", 2.0)
+        sol = linprog_core.PySimplexSolver().solve(prob)
+        self.assertEqual(sol.objective, 2.0)
+
+    def test_typo_raises(self):
+        with self.assertRaises(ValueError):
+            linprog_core.PyProblem([1.0], goal="maximse")
+
+
+class TestRemoveConstraint(unittest.TestCase):
+
+    def test_remove_changes_optimum(self):
+        prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+        prob.add_constraint([1.0, 1.0], "<=", 4.0)
+        prob.add_constraint([2.0, 1.0], "<=", 5.0)
+        prob.add_constraint([0.0, 1.0], "<=", 2.0)
+        self.assertEqual(linprog_core.PySimplexSolver().solve(prob).objective, 8.5)
+
+        prob.remove_constraint(2)
+        self.assertEqual(linprog_core.PySimplexSolver().solve(prob).objective, 9.0)
+
+    def test_out_of_range_raises(self):
+        prob = linprog_core.PyProblem([1.0], goal="max")
+        with self.assertRaises(IndexError):
+            prob.remove_constraint(0)
+
+
+class TestDimensionCheck(unittest.TestCase):
+
+    def test_wrong_length_constraint_raises_immediately(self):
+        prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+        prob.add_constraint([1.0, 1.0], "<=", 4.0)
+        with self.assertRaisesRegex(ValueError, "has 3 coefficients; expected 2"):
+            prob.add_constraint([1.0, 1.0, 1.0], "<=", 4.0)
+        self.assertEqual(prob.to_lp_string().count("<="), 1)
+
+
+class TestTextExport(unittest.TestCase):
+
+    def setUp(self):
+        self.prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+        self.prob.add_constraint([1.0, 1.0], "<=", 4.0)
+        self.prob.add_constraint([2.0, 1.0], ">=", 5.0)
+
+    def test_lp_string_has_objective_and_constraints(self):
+        text = self.prob.to_lp_string()
+        self.assertIn("Max Z = 3x0 + 2x1", text)
+        self.assertIn("x0 + x1 <= 4", text)
+        self.assertIn("2x0 + x1 >= 5", text)
+        self.assertEqual(text, str(self.prob))
+
+    def test_standard_form_string_has_equality_rows(self):
+        text = self.prob.to_standard_form_string()
+        self.assertIn("Min Z = -3x0 - 2x1", text)
+        self.assertIn("x0 + x1 + x2 = 4", text)
+        self.assertIn("2x0 + x1 - x3 = 5", text)
+
+
+class TestDual(unittest.TestCase):
+
+    def test_strong_duality(self):
+        b = [4.0, 5.0]
+        primal = linprog_core.PyProblem.from_dense(
+            [3.0, 2.0], [[1.0, 1.0], [2.0, 1.0]], ["<=", "<="], b)
+        dual = primal.dual()
+
+        p_sol = linprog_core.PySimplexSolver().solve(primal)
+        d_sol = linprog_core.PySimplexSolver().solve(dual)
+        self.assertEqual(p_sol.status, "optimal")
+        self.assertEqual(d_sol.status, "optimal")
+
+        self.assertAlmostEqual(p_sol.objective, 9.0)
+        self.assertAlmostEqual(d_sol.objective, p_sol.objective)
+
+
+class TestDensity(unittest.TestCase):
+
+    def test_nonzeros_and_density(self):
+        prob = linprog_core.PyProblem([1.0, 1.0, 1.0, 1.0], goal="max")
+        prob.add_constraint([1.0, 0.0, 0.0, 0.0], "<=", 1.0)
+        prob.add_constraint([0.0, 0.0, 2.0, 0.0], "<=", 1.0)
+        self.assertEqual(prob.num_nonzeros(), 2)
+        self.assertAlmostEqual(prob.density(), 0.25)
+
+
+if __name__ == "__main__":
+    unittest.main()
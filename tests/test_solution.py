@@ -0,0 +1,90 @@
+"""Tests for the extra information attached to PySolution."""
+
+import unittest
+import linprog_core
+
+
+def _sample_problem():
+    """max 3x + 2y s.t. x + y <= 4, 2x + y <= 5, x <= 10 (optimum (1, 3))."""
+    prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+    prob.add_constraint([1.0, 1.0], "<=", 4.0)
+    prob.add_constraint([2.0, 1.0], "<=", 5.0)
+    prob.add_constraint([1.0, 0.0], "<=", 10.0)
+    return prob
+
+
+class TestSlacksAndReducedCosts(unittest.TestCase):
+
+    def test_binding_constraints_have_zero_slack(self):
+        sol = linprog_core.PySimplexSolver().solve(_sample_problem())
+        self.assertEqual(sol.status, "optimal")
+        self.assertEqual(len(sol.slacks), 3)
+        self.assertAlmostEqual(sol.slacks[0], 0.0)
+        self.assertAlmostEqual(sol.slacks[1], 0.0)
+        self.assertAlmostEqual(sol.slacks[2], 9.0)
+
+    def test_basis_holds_nonzero_variables(self):
+        sol = linprog_core.PySimplexSolver().solve(_sample_problem())
+        self.assertEqual(sorted(sol.basis), [0, 1, 4])
+
+    def test_basic_variables_have_zero_reduced_cost(self):
+        sol = linprog_core.PySimplexSolver().solve(_sample_problem())
+        self.assertEqual(sol.reduced_costs, [0.0, 0.0])
+
+    def test_unbounded_has_no_slacks(self):
+        prob = linprog_core.PyProblem([1.0, 0.0], goal="max")
+        prob.add_constraint([1.0, -1.0], "<=", 1.0)
+        sol = linprog_core.PySimplexSolver().solve(prob)
+        self.assertEqual(sol.status, "unbounded")
+        self.assertEqual(sol.slacks, [])
+
+
+class TestUnboundedRay(unittest.TestCase):
+
+    def test_unbounded_reports_ray(self):
+        prob = linprog_core.PyProblem([1.0, 0.0], goal="max")
+        prob.add_constraint([1.0, -1.0], "<=", 1.0)
+        sol = linprog_core.PySimplexSolver().solve(prob)
+        self.assertEqual(sol.status, "unbounded")
+        self.assertEqual(sol.ray, [1.0, 1.0])
+
+    def test_optimal_has_no_ray(self):
+        self.assertIsNone(linprog_core.PySimplexSolver().solve(_sample_problem()).ray)
+
+
+class TestAsDict(unittest.TestCase):
+
+    def test_default_names(self):
+        sol = linprog_core.PySimplexSolver().solve(_sample_problem())
+        self.assertEqual(sol.as_dict(), {"x0": 1.0, "x1": 3.0})
+
+    def test_custom_names(self):
+        sol = linprog_core.PySimplexSolver().solve(_sample_problem())
+        self.assertEqual(sol.as_dict(["chairs", "tables"]), {"chairs": 1.0, "tables": 3.0})
+
+    def test_wrong_name_count_raises(self):
+        sol = linprog_core.PySimplexSolver().solve(_sample_problem())
+        with self.assertRaises(ValueError):
+            sol.as_dict(["only_one"])
+
+
+class TestRepr(unittest.TestCase):
+
+    def test_repr_shows_values_objective_and_status(self):
+        sol = linprog_core.PySimplexSolver().solve(_sample_problem())
+        text = repr(sol)
+        self.assertIn("objective: 9", text)
+        self.assertIn("status: optimal", text)
+        self.assertIn("x1", text)
+        self.assertEqual(sol.x_exact, [(1, 1), (3, 1)])
+        self.assertEqual(sol.objective_exact, (9, 1))
+
+    def test_repr_uses_exact_fractions(self):
+        prob = linprog_core.PyProblem([1.0, 0.0], goal="max")
+        prob.add_constraint([2.0, 0.0], "<=", 3.0)
+        sol = linprog_core.PySimplexSolver().solve(prob)
+        self.assertIn("3/2", repr(sol))
+
+
+if __name__ == "__main__":
+    unittest.main()
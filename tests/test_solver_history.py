@@ -80,6 +80,19 @@ class TestSimplexHistory(unittest.TestCase):
         for i in range(1, len(primals)):
             self.assertNotEqual(primals[i], primals[i - 1])
 
+    def test_simplex_steps_have_no_phase(self):
+        prob, _ = _tesseract_problem(3)
+        _, history, _stats = linprog_core.PySimplexSolver().solve_with_history(prob)
+        self.assertTrue(all(s.phase is None for s in history))
+
+    def test_steps_expose_basis_and_exact_objective(self):
+        prob, constraints = _kleeminty_problem(3)
+        _, history, _stats = linprog_core.PySimplexSolver().solve_with_history(prob)
+        for step in history:
+            self.assertEqual(len(step.basis), len(constraints))
+        self.assertEqual(history[0].basis, list(range(3, 3 + len(constraints))))
+        self.assertEqual(history[-1].objective_exact, (25, 1))
+
     def test_no_duplicate_final_step(self):
         """The final vertex must not appear twice."""
         prob, _ = _tesseract_problem(3)
@@ -141,6 +154,11 @@ class TestShadowVertexHistory(unittest.TestCase):
         for i in range(1, len(primals)):
             self.assertNotEqual(primals[i], primals[i - 1])
 
+    def test_phase_switches_after_initial_vertex(self):
+        _, history, _ = self._solve(3, _tesseract_problem)
+        self.assertEqual(history[0].phase, "auxiliary")
+        self.assertTrue(all(s.phase == "objective" for s in history[1:]))
+
     def test_tesseract_5d(self):
         sol, history, shadow_pts = self._solve(5, _tesseract_problem)
         primals = _primals(history)
@@ -0,0 +1,136 @@
+"""Tests for driving PyTableau pivots by hand."""
+
+import unittest
+import linprog_core
+
+
+def _sample_tableau():
+    """max 3x + 2y s.t. x + y <= 4, 2x + y <= 5."""
+    prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+    prob.add_constraint([1.0, 1.0], "<=", 4.0)
+    prob.add_constraint([2.0, 1.0], "<=", 5.0)
+    return prob.to_tableau()
+
+
+class TestTableauPivot(unittest.TestCase):
+
+    def test_manual_pivots_reach_optimum(self):
+        tab = _sample_tableau()
+        self.assertEqual(tab.basis(), [2, 3])
+        self.assertFalse(tab.is_optimal())
+
+        tab.pivot(1, 0)
+        self.assertEqual(tab.basis(), [2, 0])
+        self.assertAlmostEqual(tab.get(1, 1), 0.5)
+        self.assertAlmostEqual(tab.get(1, 4), 2.5)
+        self.assertFalse(tab.is_optimal())
+
+        tab.pivot(0, 1)
+        self.assertEqual(tab.basis(), [1, 0])
+        self.assertTrue(tab.is_optimal())
+        self.assertAlmostEqual(tab.get(2, 4), 9.0)
+
+    def test_pivot_on_zero_raises(self):
+        prob = linprog_core.PyProblem([1.0, 1.0], goal="max")
+        prob.add_constraint([0.0, 1.0], "<=", 3.0)
+        tab = prob.to_tableau()
+        with self.assertRaises(ValueError):
+            tab.pivot(0, 0)
+
+    def test_pivot_out_of_range_raises(self):
+        tab = _sample_tableau()
+        with self.assertRaises(IndexError):
+            tab.pivot(2, 0)
+
+
+class TestTableauState(unittest.TestCase):
+
+    def test_blocks_of_initial_tableau(self):
+        tab = _sample_tableau()
+        self.assertEqual(tab.coefficients(), [[1.0, 1.0], [2.0, 1.0]])
+        self.assertEqual(tab.slack_matrix(), [[1.0, 0.0], [0.0, 1.0]])
+        self.assertEqual(tab.rhs(), [4.0, 5.0])
+        self.assertEqual(tab.z_coeffs(), [-3.0, -2.0])
+        self.assertEqual(tab.z_slack(), [0.0, 0.0])
+        self.assertEqual(tab.z_rhs(), 0.0)
+        self.assertEqual(tab.basis(), [2, 3])
+        self.assertEqual(tab.nonbasis(), [0, 1])
+
+    def test_as_fractions_keeps_exact_values(self):
+        tab = _sample_tableau()
+        tab.pivot(1, 0)
+        fractions = tab.as_fractions()
+        self.assertEqual(len(fractions), tab.num_rows() + 1)
+        self.assertEqual(fractions[1][1], "1/2")
+        self.assertEqual(fractions[1][4], "5/2")
+
+
+class TestSolverTableau(unittest.TestCase):
+
+    def test_live_tableau_tracks_steps(self):
+        prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+        prob.add_constraint([1.0, 1.0], "<=", 4.0)
+        prob.add_constraint([2.0, 1.0], "<=", 5.0)
+        solver = linprog_core.PySimplexSolver()
+        solver.init(prob)
+        solver.find_initial_bfs()
+        self.assertEqual(solver.get_tableau().basis(), [2, 3])
+
+        solver.step()
+        solver.step()
+        tab = solver.get_tableau()
+        self.assertEqual(tab.basis(), [1, 0])
+        self.assertTrue(tab.is_optimal())
+
+    def test_current_objective_matches_last_step(self):
+        prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+        prob.add_constraint([1.0, 1.0], "<=", 4.0)
+        prob.add_constraint([2.0, 1.0], "<=", 5.0)
+        solver = linprog_core.PySimplexSolver()
+        self.assertIsNone(solver.current_objective())
+        solver.init(prob)
+        solver.find_initial_bfs()
+        solver.step()
+        solver.step()
+        self.assertAlmostEqual(solver.current_objective(), solver.last_step().objective_value)
+        self.assertAlmostEqual(solver.current_objective(), 9.0)
+
+    def test_uninitialized_raises(self):
+        with self.assertRaises(ValueError):
+            linprog_core.PySimplexSolver().get_tableau()
+
+
+class TestTableauHistory(unittest.TestCase):
+
+    def _problem(self):
+        prob = linprog_core.PyProblem([3.0, 2.0], goal="max")
+        prob.add_constraint([1.0, 1.0], "<=", 4.0)
+        prob.add_constraint([2.0, 1.0], "<=", 5.0)
+        return prob
+
+    def _count_steps(self, solver):
+        solver.init(self._problem())
+        solver.find_initial_bfs()
+        count = 0
+        while not solver.is_done():
+            solver.step()
+            count += 1
+        return count
+
+    def test_one_snapshot_per_step(self):
+        for make in (linprog_core.PySimplexSolver, linprog_core.PyShadowVertexSimplexSolver):
+            sol, snapshots = make().solve_with_tableau_history(self._problem())
+            self.assertEqual(len(snapshots), self._count_steps(make()))
+            self.assertAlmostEqual(sol.objective, 9.0)
+            step, tab = snapshots[-1]
+            self.assertEqual(step.basis, tab.basis())
+            self.assertTrue(tab.is_optimal())
+
+    def test_snapshots_are_independent_copies(self):
+        _, snapshots = linprog_core.PySimplexSolver().solve_with_tableau_history(self._problem())
+        self.assertEqual(snapshots[0][1].basis(), snapshots[0][0].basis)
+        self.assertNotEqual(snapshots[0][1].basis(), snapshots[-1][1].basis())
+
+
+if __name__ == "__main__":
+    unittest.main()